@@ -0,0 +1,42 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::compiled::Program;
+use logical_expression_pest_parser::truth_table::TruthTable;
+use std::hint::black_box;
+
+const EXPRESSION_12: &str =
+    "(A AND B) XOR (C OR !D) NAND (E XNOR F) OR (G NOR H) AND (I XOR J) OR !(K AND L)";
+
+const EXPRESSION_20: &str = "(A AND B) XOR (C OR !D) NAND (E XNOR F) OR (G NOR H) AND (I XOR J) OR !(K AND L) \
+    XOR (M NAND N) AND (O OR P) XNOR (Q AND !R) OR (S NOR T)";
+
+fn truth_table_benchmark(c: &mut Criterion) {
+    let expression: Expression = EXPRESSION_12.parse().unwrap();
+
+    let mut group = c.benchmark_group("truth_table_12_variables");
+    group.bench_function("interpreted", |b| {
+        b.iter(|| TruthTable::from_interpreted(black_box(&expression)))
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| TruthTable::from(black_box(&expression)))
+    });
+    group.finish();
+}
+
+fn program_benchmark(c: &mut Criterion) {
+    let expression: Expression = EXPRESSION_20.parse().unwrap();
+    let program = Program::compile(&expression);
+
+    let mut group = c.benchmark_group("evaluate_20_variables");
+    group.sample_size(10);
+    group.bench_function("interpreted", |b| {
+        b.iter(|| TruthTable::from_interpreted(black_box(&expression)))
+    });
+    group.bench_function("compiled_packed", |b| {
+        b.iter(|| black_box(&program).evaluate_all())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, truth_table_benchmark, program_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+use crate::ast::Expression;
+use crate::truth_table::TruthTable;
+use std::fmt::{Display, Formatter};
+
+/// A Boolean function in algebraic normal form, i.e. its Zhegalkin polynomial: a XOR of ANDs of positive literals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anf {
+    /// A sorted list of unique variables; bit `i` of every monomial refers to the variable with index `i`.
+    pub variables: Vec<char>,
+    /// The monomials with coefficient `1`, as bitmasks of their variables; `0` is the constant `1`.
+    ///
+    /// Monomials are sorted by degree and then lexicographically by their variables.
+    pub monomials: Vec<u64>,
+}
+
+impl Anf {
+    /// Returns the algebraic degree, i.e. the largest number of variables in a monomial, or `0` for constant functions.
+    pub fn degree(&self) -> usize {
+        self.monomials
+            .iter()
+            .map(|monomial| monomial.count_ones() as usize)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Converts a monomial into an [Expression], ANDing its variables.
+    ///
+    /// # Returns
+    /// The AND of the variables, or `1` for the constant monomial.
+    pub fn monomial_expression(&self, monomial: u64) -> Expression {
+        self.variables
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| monomial >> index & 1 == 1)
+            .map(|(_, &variable)| Expression::Identifier(variable))
+            .reduce(|left, right| Expression::And(Box::new(left), Box::new(right)))
+            .unwrap_or(Expression::Constant(true))
+    }
+
+    /// Converts the polynomial into an [Expression], XORing its monomials.
+    ///
+    /// # Returns
+    /// The XOR of the monomials, or `0` if there are none.
+    pub fn to_expression(&self) -> Expression {
+        self.monomials
+            .iter()
+            .map(|&monomial| self.monomial_expression(monomial))
+            .reduce(|left, right| Expression::Xor(Box::new(left), Box::new(right)))
+            .unwrap_or(Expression::Constant(false))
+    }
+}
+
+impl Display for Anf {
+    /// Formats an [Anf] as a XOR of monomials, e.g. `1 XOR A XOR (A AND B)`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_expression())
+    }
+}
+
+impl TruthTable {
+    /// Converts the table into algebraic normal form with the Möbius transform.
+    ///
+    /// The coefficient of a monomial is the XOR of the results of all rows whose set variables are a subset of
+    /// the monomial's variables; the transform computes all of them in place in `n * 2^n` steps.
+    pub fn to_anf(&self) -> Anf {
+        let mut coefficients: Vec<bool> = self.rows.iter().map(|row| row.result).collect();
+
+        for index in 0..self.variables.len() {
+            let bit = 1 << index;
+            for mask in 0..coefficients.len() {
+                if mask & bit != 0 {
+                    coefficients[mask] ^= coefficients[mask ^ bit];
+                }
+            }
+        }
+
+        let mut monomials: Vec<u64> = (0..coefficients.len())
+            .filter(|&mask| coefficients[mask])
+            .map(|mask| mask as u64)
+            .collect();
+        monomials.sort_by_key(|&monomial| {
+            let indices: Vec<u32> = (0..u64::BITS)
+                .filter(|index| monomial >> index & 1 == 1)
+                .collect();
+            (indices.len(), indices)
+        });
+
+        Anf {
+            variables: self.variables.clone(),
+            monomials,
+        }
+    }
+}
+
+impl Expression {
+    /// Converts the expression into algebraic normal form.
+    pub fn to_anf(&self) -> Anf {
+        TruthTable::from(self).to_anf()
+    }
+}
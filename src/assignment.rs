@@ -0,0 +1,178 @@
+use crate::ast::Expression;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// A source of boolean values for variables of an [Expression].
+pub trait Assignment {
+    /// Returns the value of a variable, or `None` if the variable is not assigned.
+    fn value(&self, variable: char) -> Option<bool>;
+}
+
+impl Assignment for HashMap<char, bool> {
+    fn value(&self, variable: char) -> Option<bool> {
+        self.get(&variable).copied()
+    }
+}
+
+/// An [Assignment] backed by a slice of values indexed by variable order.
+#[derive(Debug, Clone, Copy)]
+pub struct SliceAssignment<'a> {
+    /// The assigned variables.
+    variables: &'a [char],
+    /// The value of each variable, at the same index as the variable.
+    values: &'a [bool],
+}
+
+impl<'a> SliceAssignment<'a> {
+    /// Creates a [SliceAssignment].
+    ///
+    /// # Arguments
+    /// * `variables` - The assigned variables, e.g. the result of [Expression::variables].
+    /// * `values` - The value of each variable, at the same index as the variable.
+    ///
+    /// # Panics
+    /// Panics if `variables` and `values` have different lengths.
+    pub fn new(variables: &'a [char], values: &'a [bool]) -> Self {
+        assert_eq!(
+            variables.len(),
+            values.len(),
+            "every variable must have exactly one value"
+        );
+        Self { variables, values }
+    }
+}
+
+impl Assignment for SliceAssignment<'_> {
+    fn value(&self, variable: char) -> Option<bool> {
+        self.variables
+            .iter()
+            .position(|&v| v == variable)
+            .map(|index| self.values[index])
+    }
+}
+
+/// An [Assignment] backed by a bitmask, where bit `i` is the value of the `i`-th variable.
+///
+/// With sorted variables this matches the row numbering of [crate::truth_table::TruthTable].
+#[derive(Debug, Clone, Copy)]
+pub struct BitmaskAssignment<'a> {
+    /// The assigned variables.
+    variables: &'a [char],
+    /// The values of the variables, one bit per variable.
+    mask: u64,
+}
+
+impl<'a> BitmaskAssignment<'a> {
+    /// Creates a [BitmaskAssignment].
+    ///
+    /// # Arguments
+    /// * `variables` - The assigned variables, e.g. the result of [Expression::variables].
+    /// * `mask` - The values of the variables, bit `i` being the value of `variables[i]`.
+    ///
+    /// # Panics
+    /// Panics if there are more than 64 variables.
+    pub fn new(variables: &'a [char], mask: u64) -> Self {
+        assert!(
+            variables.len() <= u64::BITS as usize,
+            "a bitmask can hold at most 64 variables"
+        );
+        Self { variables, mask }
+    }
+}
+
+impl Assignment for BitmaskAssignment<'_> {
+    fn value(&self, variable: char) -> Option<bool> {
+        self.variables
+            .iter()
+            .position(|&v| v == variable)
+            .map(|index| self.mask >> index & 1 == 1)
+    }
+}
+
+/// Custom evaluation error created using the [thiserror] crate.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EvaluationError {
+    /// Error for variables of the expression that have no value in the assignment.
+    #[error("Missing values for variables: {}", format_variables(.0))]
+    MissingVariables(Vec<char>),
+}
+
+/// Formats a list of variables separated by commas, or `none` if it is empty.
+pub fn format_variables(variables: &[char]) -> String {
+    if variables.is_empty() {
+        return "none".to_string();
+    }
+
+    variables
+        .iter()
+        .map(char::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Expression {
+    /// Strictly evaluates a logical expression with given variables.
+    ///
+    /// # Arguments
+    /// * `assignment` - An [Assignment] providing the value of every variable of the expression.
+    ///
+    /// # Returns
+    /// A [Result] containing the `bool` result of the expression or an [EvaluationError].
+    ///
+    /// # Errors
+    /// Returns [EvaluationError::MissingVariables] naming every variable that is not assigned.
+    pub fn try_evaluate<A: Assignment + ?Sized>(
+        &self,
+        assignment: &A,
+    ) -> Result<bool, EvaluationError> {
+        let missing: Vec<char> = self
+            .variables()
+            .into_iter()
+            .filter(|&variable| assignment.value(variable).is_none())
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(EvaluationError::MissingVariables(missing));
+        }
+
+        Ok(self.evaluate_lenient(assignment))
+    }
+
+    /// Leniently evaluates a logical expression with given variables.
+    ///
+    /// Variables that are not assigned are treated as `false`.
+    ///
+    /// # Arguments
+    /// * `assignment` - An [Assignment] providing values of the variables.
+    ///
+    /// # Returns
+    /// A `bool` result of the expression evaluated with given variables.
+    pub fn evaluate_lenient<A: Assignment + ?Sized>(&self, assignment: &A) -> bool {
+        match self {
+            Expression::Constant(value) => *value,
+            Expression::Identifier(iden) => assignment.value(*iden).unwrap_or(false),
+            Expression::Not(expr) => !expr.evaluate_lenient(assignment),
+            Expression::And(left, right) => {
+                left.evaluate_lenient(assignment) && right.evaluate_lenient(assignment)
+            }
+            Expression::Nand(left, right) => {
+                !(left.evaluate_lenient(assignment) && right.evaluate_lenient(assignment))
+            }
+            Expression::Or(left, right) => {
+                left.evaluate_lenient(assignment) || right.evaluate_lenient(assignment)
+            }
+            Expression::Nor(left, right) => {
+                !(left.evaluate_lenient(assignment) || right.evaluate_lenient(assignment))
+            }
+            Expression::Xor(left, right) => {
+                left.evaluate_lenient(assignment) ^ right.evaluate_lenient(assignment)
+            }
+            Expression::Xnor(left, right) => {
+                !(left.evaluate_lenient(assignment) ^ right.evaluate_lenient(assignment))
+            }
+            Expression::Implies(left, right) => {
+                !left.evaluate_lenient(assignment) || right.evaluate_lenient(assignment)
+            }
+        }
+    }
+}
@@ -1,10 +1,14 @@
-use crate::parser::Rule;
+use crate::parser::{ParserError, Rule, parse};
 use pest::iterators::Pair;
 use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 /// An AST node that represents a logical expression recursively.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Expression {
+    /// A constant `bool` value, produced by transformations such as partial evaluation.
+    Constant(bool),
     /// A `char` identifier.
     Identifier(char),
     /// A unary NOT operation.
@@ -21,33 +25,23 @@ pub enum Expression {
     Xor(Box<Expression>, Box<Expression>),
     /// A binary XNOR operation
     Xnor(Box<Expression>, Box<Expression>),
+    /// A binary implication, `1` unless the left operand is `1` and the right one is `0`
+    Implies(Box<Expression>, Box<Expression>),
 }
 
 impl Expression {
     /// Evaluates a logical expression with given variables and return boolean.
     ///
+    /// Variables missing from `variables` are treated as `false`; this is the same as [Expression::evaluate_lenient].
+    /// Use [Expression::try_evaluate] to get an error for missing variables instead.
+    ///
     /// # Arguments
     /// * `variables` - `HashMap<char, bool>` that maps an identifier to its boolean value.
     ///
     /// # Returns
     /// A `bool` result of the expression evaluated with given variables.
     pub fn evaluate(&self, variables: &HashMap<char, bool>) -> bool {
-        match self {
-            Expression::Identifier(iden) => variables.get(iden).cloned().unwrap_or(false),
-            Expression::Not(expr) => !expr.evaluate(variables),
-            Expression::And(left, right) => left.evaluate(variables) && right.evaluate(variables),
-            Expression::Nand(left, right) => {
-                !(left.evaluate(variables) && right.evaluate(variables))
-            }
-            Expression::Or(left, right) => left.evaluate(variables) || right.evaluate(variables),
-            Expression::Nor(left, right) => {
-                !(left.evaluate(variables) || right.evaluate(variables))
-            }
-            Expression::Xor(left, right) => left.evaluate(variables) ^ right.evaluate(variables),
-            Expression::Xnor(left, right) => {
-                !(left.evaluate(variables) ^ right.evaluate(variables))
-            }
-        }
+        self.evaluate_lenient(variables)
     }
 
     /// Recursively creates an [Expression] from result of Pest parsing.
@@ -124,6 +118,16 @@ impl Expression {
             }
 
             Rule::expression => {
+                let mut inner = pair.into_inner();
+                let left = Self::ast(inner.next().unwrap());
+
+                match inner.nth(1) {
+                    Some(right) => Self::Implies(Box::new(left), Box::new(Self::ast(right))),
+                    None => left,
+                }
+            }
+
+            Rule::disjunction => {
                 let mut inner = pair.into_inner();
                 let mut left = Self::ast(inner.next().unwrap());
 
@@ -139,6 +143,10 @@ impl Expression {
                 left
             }
 
+            Rule::dontcare => Self::ast(pair.into_inner().nth(1).unwrap()),
+
+            Rule::specification => Self::ast(pair.into_inner().next().unwrap()),
+
             Rule::file => Self::ast(pair.into_inner().next().unwrap()),
 
             _ => unreachable!(),
@@ -164,6 +172,7 @@ impl Expression {
     /// * `variables` - A mutable `HashSet<char>` to insert all found identifier into.
     fn all_variables_set(&self, variables: &mut HashSet<char>) {
         match self {
+            Expression::Constant(_) => {}
             Expression::Identifier(ident) => {
                 variables.insert(*ident);
             }
@@ -175,10 +184,99 @@ impl Expression {
             | Expression::Or(left, right)
             | Expression::Nor(left, right)
             | Expression::Xor(left, right)
-            | Expression::Xnor(left, right) => {
+            | Expression::Xnor(left, right)
+            | Expression::Implies(left, right) => {
                 left.all_variables_set(variables);
                 right.all_variables_set(variables);
             }
         }
     }
 }
+
+impl Expression {
+    /// Returns the binding strength of the top-level operator, as defined by the grammar.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression::Implies(..) => 0,
+            Expression::Or(..) | Expression::Nor(..) => 1,
+            Expression::And(..) | Expression::Nand(..) => 2,
+            Expression::Xor(..) | Expression::Xnor(..) => 3,
+            Expression::Constant(_) | Expression::Identifier(_) | Expression::Not(_) => 4,
+        }
+    }
+
+    /// Helper method for formatting an operand, adding parentheses where the grammar requires them.
+    ///
+    /// # Arguments
+    /// * `f` - The formatter to write into.
+    /// * `precedence` - The precedence of the parent operator.
+    /// * `is_right` - Whether the operand is the right one, which needs parentheses on equal precedence since operators are left-associative.
+    ///   Implication is right-associative, so there the left operand needs them instead.
+    fn fmt_operand(
+        &self,
+        f: &mut Formatter<'_>,
+        precedence: u8,
+        is_right: bool,
+    ) -> std::fmt::Result {
+        let is_inner = is_right != matches!(self, Expression::Implies(..));
+        if self.precedence() < precedence || (is_inner && self.precedence() == precedence) {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl Display for Expression {
+    /// Formats an [Expression] using the operator keywords, so that the output can be parsed back.
+    ///
+    /// Constants, which have no syntax in the grammar, are written as `1` and `0`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (left, operator, right) = match self {
+            Expression::Constant(value) => return write!(f, "{}", if *value { '1' } else { '0' }),
+            Expression::Identifier(iden) => return write!(f, "{}", iden),
+            Expression::Not(expr) => {
+                write!(f, "NOT ")?;
+                return expr.fmt_operand(f, self.precedence(), false);
+            }
+            Expression::And(left, right) => (left, "AND", right),
+            Expression::Nand(left, right) => (left, "NAND", right),
+            Expression::Or(left, right) => (left, "OR", right),
+            Expression::Nor(left, right) => (left, "NOR", right),
+            Expression::Xor(left, right) => (left, "XOR", right),
+            Expression::Xnor(left, right) => (left, "XNOR", right),
+            Expression::Implies(left, right) => (left, "->", right),
+        };
+
+        left.fmt_operand(f, self.precedence(), false)?;
+        write!(f, " {} ", operator)?;
+        right.fmt_operand(f, self.precedence(), true)
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParserError;
+
+    /// Parses a single logical expression from a string.
+    ///
+    /// # Errors
+    /// Returns [ParserError::EmptyInputError] if `input` contains no expression and
+    /// [ParserError::PestError] if it is not a valid expression.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim_end();
+        if input.is_empty() {
+            return Err(ParserError::EmptyInputError);
+        }
+
+        let content = format!("{}\n", input);
+        let file_pair = parse(&content)?
+            .next()
+            .ok_or(ParserError::EmptyInputError)?;
+        let expression_pair = file_pair
+            .into_inner()
+            .next()
+            .ok_or(ParserError::EmptyInputError)?;
+
+        Ok(Self::ast(expression_pair))
+    }
+}
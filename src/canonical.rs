@@ -0,0 +1,127 @@
+use crate::ast::Expression;
+use crate::truth_table::TruthTable;
+
+impl TruthTable {
+    /// Collects the minterm numbers of the table.
+    ///
+    /// A minterm number is the index of a row with result `1`, so the first variable is the least significant bit.
+    /// Don't-care rows are never minterms.
+    ///
+    /// # Returns
+    /// A sorted `Vec<usize>` of minterm numbers.
+    pub fn minterms(&self) -> Vec<usize> {
+        self.row_indices(true)
+    }
+
+    /// Collects the maxterm numbers of the table, i.e. the indices of care rows with result `0`.
+    ///
+    /// # Returns
+    /// A sorted `Vec<usize>` of maxterm numbers.
+    pub fn maxterms(&self) -> Vec<usize> {
+        self.row_indices(false)
+    }
+
+    /// Converts the table into its canonical sum of minterms.
+    ///
+    /// # Returns
+    /// An [Expression] ORing one full conjunction per minterm, or `0` if there are none.
+    pub fn to_sum_of_minterms(&self) -> Expression {
+        self.minterms()
+            .into_iter()
+            .map(|minterm| self.minterm_expression(minterm))
+            .reduce(|left, right| Expression::Or(Box::new(left), Box::new(right)))
+            .unwrap_or(Expression::Constant(false))
+    }
+
+    /// Converts the table into its canonical product of maxterms.
+    ///
+    /// # Returns
+    /// An [Expression] ANDing one full disjunction per maxterm, or `1` if there are none.
+    pub fn to_product_of_maxterms(&self) -> Expression {
+        self.maxterms()
+            .into_iter()
+            .map(|maxterm| self.maxterm_expression(maxterm))
+            .reduce(|left, right| Expression::And(Box::new(left), Box::new(right)))
+            .unwrap_or(Expression::Constant(true))
+    }
+
+    /// Formats the minterms in `Σm(1,3,5)` notation, followed by don't-cares as in `Σm(1,3) + d(5)`.
+    pub fn minterm_notation(&self) -> String {
+        format!(
+            "Σm({}){}",
+            format_numbers(&self.minterms()),
+            self.dont_care_notation(" + d")
+        )
+    }
+
+    /// Formats the maxterms in `ΠM(0,2)` notation, followed by don't-cares as in `ΠM(0,2) · D(5)`.
+    pub fn maxterm_notation(&self) -> String {
+        format!(
+            "ΠM({}){}",
+            format_numbers(&self.maxterms()),
+            self.dont_care_notation(" · D")
+        )
+    }
+
+    /// Helper method that formats the don't-cares with a prefix, or returns an empty string if there are none.
+    fn dont_care_notation(&self, prefix: &str) -> String {
+        let dont_cares = self.dont_cares();
+        if dont_cares.is_empty() {
+            return String::new();
+        }
+
+        format!("{}({})", prefix, format_numbers(&dont_cares))
+    }
+
+    /// Helper method that collects indices of rows with the given result.
+    fn row_indices(&self, result: bool) -> Vec<usize> {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| !row.dont_care && row.result == result)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Helper method that builds the conjunction which is `1` only in row `minterm`.
+    fn minterm_expression(&self, minterm: usize) -> Expression {
+        self.literals(minterm, false)
+            .reduce(|left, right| Expression::And(Box::new(left), Box::new(right)))
+            .unwrap_or(Expression::Constant(true))
+    }
+
+    /// Helper method that builds the disjunction which is `0` only in row `maxterm`.
+    fn maxterm_expression(&self, maxterm: usize) -> Expression {
+        self.literals(maxterm, true)
+            .reduce(|left, right| Expression::Or(Box::new(left), Box::new(right)))
+            .unwrap_or(Expression::Constant(false))
+    }
+
+    /// Helper method that yields one literal per variable, negated where the variable's bit in `row` differs from `negate_ones`.
+    ///
+    /// # Arguments
+    /// * `row` - The row number.
+    /// * `negate_ones` - `false` to negate variables that are `0` in the row (minterms), `true` to negate those that are `1` (maxterms).
+    fn literals(&self, row: usize, negate_ones: bool) -> impl Iterator<Item = Expression> + '_ {
+        self.variables
+            .iter()
+            .enumerate()
+            .map(move |(index, &variable)| {
+                let identifier = Expression::Identifier(variable);
+                if (row >> index & 1 == 1) == negate_ones {
+                    Expression::Not(Box::new(identifier))
+                } else {
+                    identifier
+                }
+            })
+    }
+}
+
+/// Formats numbers separated by commas.
+fn format_numbers(numbers: &[usize]) -> String {
+    numbers
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,99 @@
+use crate::ast::Expression;
+use crate::compiled::Program;
+use crate::truth_table::TruthTable;
+use std::fmt::{Display, Formatter};
+
+/// The classification of a Boolean function by its satisfying assignments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Classification {
+    /// Every assignment satisfies the function.
+    Tautology,
+    /// No assignment satisfies the function.
+    Contradiction,
+    /// Some assignments satisfy the function and some do not.
+    Contingent,
+}
+
+impl Classification {
+    /// Classifies a function by its number of satisfying assignments.
+    ///
+    /// # Arguments
+    /// * `satisfying` - The number of satisfying assignments.
+    /// * `total` - The number of assignments considered.
+    pub fn from_count(satisfying: usize, total: usize) -> Self {
+        if satisfying == total {
+            Classification::Tautology
+        } else if satisfying == 0 {
+            Classification::Contradiction
+        } else {
+            Classification::Contingent
+        }
+    }
+}
+
+impl Display for Classification {
+    /// Formats a [Classification] as a lowercase word.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Classification::Tautology => write!(f, "tautology"),
+            Classification::Contradiction => write!(f, "contradiction"),
+            Classification::Contingent => write!(f, "contingent"),
+        }
+    }
+}
+
+impl TruthTable {
+    /// Counts the rows that are not don't-cares.
+    pub fn care_count(&self) -> usize {
+        self.rows.iter().filter(|row| !row.dont_care).count()
+    }
+
+    /// Counts the care rows whose result is `1`.
+    pub fn satisfying_count(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| !row.dont_care && row.result)
+            .count()
+    }
+
+    /// Classifies the function of the table, ignoring don't-care rows.
+    ///
+    /// A table without care rows is a tautology.
+    pub fn classify(&self) -> Classification {
+        Classification::from_count(self.satisfying_count(), self.care_count())
+    }
+}
+
+impl Expression {
+    /// Counts the assignments of the variables of the expression that make it `1`.
+    ///
+    /// The rows are evaluated 64 at a time by a compiled [Program] without building a truth table.
+    pub fn satisfying_count(&self) -> usize {
+        Program::compile(self)
+            .evaluate_all()
+            .iter()
+            .map(|block| block.count_ones() as usize)
+            .sum()
+    }
+
+    /// Classifies the expression as a tautology, a contradiction or contingent.
+    pub fn classify(&self) -> Classification {
+        let total = 1 << self.variables().len();
+        Classification::from_count(self.satisfying_count(), total)
+    }
+
+    /// Checks whether the expression is `1` for every assignment.
+    pub fn is_tautology(&self) -> bool {
+        self.classify() == Classification::Tautology
+    }
+
+    /// Checks whether the expression is `0` for every assignment.
+    pub fn is_contradiction(&self) -> bool {
+        self.classify() == Classification::Contradiction
+    }
+
+    /// Checks whether some assignment makes the expression `1`.
+    pub fn is_satisfiable(&self) -> bool {
+        self.classify() != Classification::Contradiction
+    }
+}
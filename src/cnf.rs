@@ -0,0 +1,317 @@
+use crate::ast::Expression;
+use crate::literal::{Literal, Variable};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+/// A disjunction of literals.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Clause {
+    /// Sorted, unique literals of the clause. An empty clause is unsatisfiable.
+    pub literals: Vec<Literal>,
+}
+
+/// A formula in conjunctive normal form, i.e. a conjunction of clauses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cnf {
+    /// The clauses of the formula. A formula without clauses is always true.
+    pub clauses: Vec<Clause>,
+}
+
+impl Clause {
+    /// Creates a [Clause] from literals, sorting them and removing duplicates.
+    pub fn new(literals: impl IntoIterator<Item = Literal>) -> Self {
+        let literals: BTreeSet<Literal> = literals.into_iter().collect();
+        Self {
+            literals: literals.into_iter().collect(),
+        }
+    }
+
+    /// Checks whether the clause contains a literal together with its negation.
+    pub fn is_tautology(&self) -> bool {
+        self.literals
+            .windows(2)
+            .any(|pair| pair[0].variable == pair[1].variable)
+    }
+
+    /// Checks whether every literal of this clause is also in `other`, making `other` redundant next to this clause.
+    pub fn subsumes(&self, other: &Clause) -> bool {
+        self.literals.len() <= other.literals.len()
+            && self
+                .literals
+                .iter()
+                .all(|literal| other.literals.binary_search(literal).is_ok())
+    }
+
+    /// Evaluates the clause with given variables; missing variables are treated as `false`.
+    pub fn evaluate(&self, variables: &HashMap<Variable, bool>) -> bool {
+        self.literals.iter().any(|literal| {
+            literal.evaluate(variables.get(&literal.variable).copied().unwrap_or(false))
+        })
+    }
+}
+
+impl Cnf {
+    /// Collects all unique variables of the formula.
+    ///
+    /// # Returns
+    /// A sorted `Vec<Variable>`, with input variables before auxiliary ones.
+    pub fn variables(&self) -> Vec<Variable> {
+        let variables: BTreeSet<Variable> = self
+            .clauses
+            .iter()
+            .flat_map(|clause| clause.literals.iter().map(|literal| literal.variable))
+            .collect();
+        variables.into_iter().collect()
+    }
+
+    /// Evaluates the formula with given variables; missing variables are treated as `false`.
+    pub fn evaluate(&self, variables: &HashMap<Variable, bool>) -> bool {
+        self.clauses.iter().all(|clause| clause.evaluate(variables))
+    }
+
+    /// Formats the formula in the DIMACS CNF format used by SAT solvers.
+    ///
+    /// Variables are numbered from `1` in the order of [Cnf::variables], and the mapping is written in comment lines.
+    pub fn to_dimacs(&self) -> String {
+        let variables = self.variables();
+        let mut dimacs = String::new();
+
+        for (index, variable) in variables.iter().enumerate() {
+            dimacs.push_str(&format!("c {} {}\n", index + 1, variable));
+        }
+        dimacs.push_str(&format!(
+            "p cnf {} {}\n",
+            variables.len(),
+            self.clauses.len()
+        ));
+
+        for clause in &self.clauses {
+            for literal in &clause.literals {
+                let number = variables.binary_search(&literal.variable).unwrap() + 1;
+                if literal.positive {
+                    dimacs.push_str(&format!("{} ", number));
+                } else {
+                    dimacs.push_str(&format!("-{} ", number));
+                }
+            }
+            dimacs.push_str("0\n");
+        }
+
+        dimacs
+    }
+
+    /// Helper method that removes tautological, duplicate and subsumed clauses.
+    fn simplified(clauses: Vec<Clause>) -> Self {
+        let mut clauses: Vec<Clause> = clauses
+            .into_iter()
+            .filter(|clause| !clause.is_tautology())
+            .collect::<BTreeSet<Clause>>()
+            .into_iter()
+            .collect();
+        clauses.sort_by_key(|clause| clause.literals.len());
+
+        let mut kept: Vec<Clause> = Vec::with_capacity(clauses.len());
+        for clause in clauses {
+            if !kept.iter().any(|other| other.subsumes(&clause)) {
+                kept.push(clause);
+            }
+        }
+
+        Self { clauses: kept }
+    }
+}
+
+impl Display for Clause {
+    /// Formats a [Clause] as a parenthesized disjunction.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.literals.is_empty() {
+            return write!(f, "0");
+        }
+
+        write!(f, "(")?;
+        for (index, literal) in self.literals.iter().enumerate() {
+            if index > 0 {
+                write!(f, " OR ")?;
+            }
+            write!(f, "{}", literal)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Display for Cnf {
+    /// Formats a [Cnf] as a conjunction of clauses.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.clauses.is_empty() {
+            return write!(f, "1");
+        }
+
+        for (index, clause) in self.clauses.iter().enumerate() {
+            if index > 0 {
+                write!(f, " AND ")?;
+            }
+            write!(f, "{}", clause)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Expression {
+    /// Converts the expression into an equivalent CNF by distributing OR over AND.
+    ///
+    /// The result is logically equivalent to the expression, but can be exponentially larger.
+    /// Tautological, duplicate and subsumed clauses are removed.
+    ///
+    /// # Returns
+    /// A [Cnf] over the input variables only.
+    pub fn to_cnf(&self) -> Cnf {
+        Cnf::simplified(distribute(&self.to_nnf()))
+    }
+
+    /// Converts the expression into an equisatisfiable CNF using the Tseitin encoding.
+    ///
+    /// Every operator gets a fresh [Variable::Auxiliary] defined to be equivalent to it, so the result is linear in the size
+    /// of the expression. Every satisfying assignment of the expression extends to exactly one satisfying assignment of the CNF.
+    ///
+    /// # Returns
+    /// A [Cnf] over the input and auxiliary variables.
+    pub fn to_tseitin_cnf(&self) -> Cnf {
+        let folded = self.partial_evaluate(&HashMap::new());
+        if let Expression::Constant(value) = folded {
+            let clauses = if value { vec![] } else { vec![Clause::new([])] };
+            return Cnf { clauses };
+        }
+
+        let mut encoder = TseitinEncoder::default();
+        let root = encoder.encode(&folded);
+        encoder.clauses.push(Clause::new([root]));
+
+        Cnf {
+            clauses: encoder.clauses,
+        }
+    }
+}
+
+/// Recursively converts an NNF expression into clauses.
+fn distribute(expression: &Expression) -> Vec<Clause> {
+    match expression {
+        Expression::Constant(true) => vec![],
+        Expression::Constant(false) => vec![Clause::new([])],
+        Expression::Identifier(iden) => {
+            vec![Clause::new([Literal::positive(Variable::Input(*iden))])]
+        }
+        Expression::Not(expr) => match **expr {
+            Expression::Identifier(iden) => {
+                vec![Clause::new([Literal::negative(Variable::Input(iden))])]
+            }
+            _ => unreachable!(),
+        },
+        Expression::And(left, right) => {
+            let mut clauses = distribute(left);
+            clauses.extend(distribute(right));
+            Cnf::simplified(clauses).clauses
+        }
+        Expression::Or(left, right) => {
+            let left = distribute(left);
+            let right = distribute(right);
+            let clauses = left
+                .iter()
+                .flat_map(|left_clause| {
+                    right.iter().map(move |right_clause| {
+                        Clause::new(
+                            left_clause
+                                .literals
+                                .iter()
+                                .chain(&right_clause.literals)
+                                .copied(),
+                        )
+                    })
+                })
+                .collect();
+            Cnf::simplified(clauses).clauses
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// The kind of a gate defined by an auxiliary variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Gate {
+    And,
+    Or,
+    Xor,
+}
+
+/// Helper state for the Tseitin encoding.
+#[derive(Default)]
+struct TseitinEncoder {
+    /// Clauses emitted so far.
+    clauses: Vec<Clause>,
+    /// The number of auxiliary variables introduced so far.
+    auxiliary_count: usize,
+    /// Auxiliary variables of already encoded gates, used to share identical subexpressions.
+    gates: HashMap<(Gate, Literal, Literal), Literal>,
+}
+
+impl TseitinEncoder {
+    /// Recursively encodes a constant-free expression and returns the literal equivalent to it.
+    fn encode(&mut self, expression: &Expression) -> Literal {
+        match expression {
+            Expression::Identifier(iden) => Literal::positive(Variable::Input(*iden)),
+            Expression::Not(expr) => self.encode(expr).negated(),
+            Expression::And(left, right) => self.gate(Gate::And, left, right),
+            Expression::Nand(left, right) => self.gate(Gate::And, left, right).negated(),
+            Expression::Or(left, right) => self.gate(Gate::Or, left, right),
+            Expression::Nor(left, right) => self.gate(Gate::Or, left, right).negated(),
+            Expression::Xor(left, right) => self.gate(Gate::Xor, left, right),
+            Expression::Xnor(left, right) => self.gate(Gate::Xor, left, right).negated(),
+            Expression::Implies(left, right) => {
+                let a = self.encode(left);
+                let b = self.encode(right);
+                self.define(Gate::Or, a.negated(), b)
+            }
+            Expression::Constant(_) => unreachable!(),
+        }
+    }
+
+    /// Introduces an auxiliary variable equivalent to a gate and emits its defining clauses.
+    fn gate(&mut self, gate: Gate, left: &Expression, right: &Expression) -> Literal {
+        let a = self.encode(left);
+        let b = self.encode(right);
+        self.define(gate, a, b)
+    }
+
+    /// Introduces an auxiliary variable equivalent to a gate over two literals, unless the gate is already encoded.
+    fn define(&mut self, gate: Gate, a: Literal, b: Literal) -> Literal {
+        if let Some(&output) = self.gates.get(&(gate, a, b)) {
+            return output;
+        }
+
+        self.auxiliary_count += 1;
+        let x = Literal::positive(Variable::Auxiliary(self.auxiliary_count));
+        self.gates.insert((gate, a, b), x);
+
+        let definition = match gate {
+            Gate::And => vec![
+                vec![x.negated(), a],
+                vec![x.negated(), b],
+                vec![x, a.negated(), b.negated()],
+            ],
+            Gate::Or => vec![
+                vec![x, a.negated()],
+                vec![x, b.negated()],
+                vec![x.negated(), a, b],
+            ],
+            Gate::Xor => vec![
+                vec![x.negated(), a, b],
+                vec![x.negated(), a.negated(), b.negated()],
+                vec![x, a.negated(), b],
+                vec![x, a, b.negated()],
+            ],
+        };
+        self.clauses.extend(definition.into_iter().map(Clause::new));
+
+        x
+    }
+}
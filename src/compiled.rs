@@ -0,0 +1,208 @@
+use crate::ast::Expression;
+use std::collections::HashMap;
+
+/// Bit patterns of the first six variables within a block of 64 rows.
+///
+/// Bit `r` of `VARIABLE_PATTERNS[i]` is the value of variable `i` in row `r` of the block.
+const VARIABLE_PATTERNS: [u64; 6] = [
+    0xAAAA_AAAA_AAAA_AAAA,
+    0xCCCC_CCCC_CCCC_CCCC,
+    0xF0F0_F0F0_F0F0_F0F0,
+    0xFF00_FF00_FF00_FF00,
+    0xFFFF_0000_FFFF_0000,
+    0xFFFF_FFFF_0000_0000,
+];
+
+/// The number of rows evaluated by one pass over a [Program].
+pub const BLOCK_SIZE: usize = 64;
+
+/// A single instruction of a compiled [Program].
+///
+/// Each instruction writes to the register with the same index as the instruction and reads only earlier registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// Loads a constant into every lane.
+    Constant(bool),
+    /// Loads the values of the variable with the given index.
+    Variable(usize),
+    /// Negates a register.
+    Not(usize),
+    /// ANDs two registers.
+    And(usize, usize),
+    /// NANDs two registers.
+    Nand(usize, usize),
+    /// ORs two registers.
+    Or(usize, usize),
+    /// NORs two registers.
+    Nor(usize, usize),
+    /// XORs two registers.
+    Xor(usize, usize),
+    /// XNORs two registers.
+    Xnor(usize, usize),
+    /// Implies the second register by the first one.
+    Implies(usize, usize),
+}
+
+/// A flat, bit-parallel program compiled from an [Expression].
+///
+/// Every register is a `u64` whose bits are the values of a subexpression in 64 consecutive truth table rows,
+/// so one pass over the instructions evaluates 64 rows at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    /// A sorted list of unique variables; the variable with index `i` is bit `i` of the row number.
+    pub variables: Vec<char>,
+    /// Instructions in evaluation order. The last instruction computes the result.
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Compiles an [Expression] into a [Program].
+    ///
+    /// Identical subexpressions are compiled only once.
+    ///
+    /// # Arguments
+    /// * `expression` - An AST node that represents a parsed logical expression.
+    pub fn compile(expression: &Expression) -> Self {
+        let variables = expression.variables();
+        let mut compiler = Compiler {
+            variables: &variables,
+            instructions: Vec::new(),
+            registers: HashMap::new(),
+        };
+        compiler.compile(expression);
+        let instructions = compiler.instructions;
+
+        Self {
+            variables,
+            instructions,
+        }
+    }
+
+    /// Returns the number of rows of the truth table this program evaluates.
+    pub fn rows_length(&self) -> usize {
+        1 << self.variables.len()
+    }
+
+    /// Returns the number of 64-row blocks needed to cover all rows.
+    pub fn blocks_length(&self) -> usize {
+        self.rows_length().div_ceil(BLOCK_SIZE)
+    }
+
+    /// Evaluates one block of 64 rows.
+    ///
+    /// # Arguments
+    /// * `block` - The index of the block, covering rows `block * 64` to `block * 64 + 63`.
+    /// * `registers` - A scratch buffer reused between calls to avoid allocations.
+    ///
+    /// # Returns
+    /// A `u64` whose bit `r` is the result for row `block * 64 + r`. Bits of rows past the end of the table are zero.
+    pub fn evaluate_block(&self, block: usize, registers: &mut Vec<u64>) -> u64 {
+        registers.clear();
+
+        for instruction in &self.instructions {
+            let value = match *instruction {
+                Instruction::Constant(value) => {
+                    if value {
+                        u64::MAX
+                    } else {
+                        0
+                    }
+                }
+                Instruction::Variable(index) => match VARIABLE_PATTERNS.get(index) {
+                    Some(&pattern) => pattern,
+                    None if block >> (index - VARIABLE_PATTERNS.len()) & 1 == 1 => u64::MAX,
+                    None => 0,
+                },
+                Instruction::Not(operand) => !registers[operand],
+                Instruction::And(left, right) => registers[left] & registers[right],
+                Instruction::Nand(left, right) => !(registers[left] & registers[right]),
+                Instruction::Or(left, right) => registers[left] | registers[right],
+                Instruction::Nor(left, right) => !(registers[left] | registers[right]),
+                Instruction::Xor(left, right) => registers[left] ^ registers[right],
+                Instruction::Xnor(left, right) => !(registers[left] ^ registers[right]),
+                Instruction::Implies(left, right) => !registers[left] | registers[right],
+            };
+            registers.push(value);
+        }
+
+        let result = registers.last().copied().unwrap_or(0);
+        match self.rows_length() {
+            rows_length if rows_length < BLOCK_SIZE => result & ((1 << rows_length) - 1),
+            _ => result,
+        }
+    }
+
+    /// Evaluates all rows.
+    ///
+    /// # Returns
+    /// A `Vec<u64>` of results packed by [Program::evaluate_block], one word per block.
+    pub fn evaluate_all(&self) -> Vec<u64> {
+        let mut registers = Vec::with_capacity(self.instructions.len());
+        (0..self.blocks_length())
+            .map(|block| self.evaluate_block(block, &mut registers))
+            .collect()
+    }
+
+    /// Evaluates a single row.
+    ///
+    /// # Arguments
+    /// * `row` - The row number, whose bit `i` is the value of variable `i`.
+    pub fn evaluate_row(&self, row: usize) -> bool {
+        let mut registers = Vec::with_capacity(self.instructions.len());
+        self.evaluate_block(row / BLOCK_SIZE, &mut registers) >> (row % BLOCK_SIZE) & 1 == 1
+    }
+}
+
+/// Helper state for compiling an [Expression] into instructions.
+struct Compiler<'a> {
+    /// Sorted variables of the compiled expression.
+    variables: &'a [char],
+    /// Instructions emitted so far.
+    instructions: Vec<Instruction>,
+    /// Registers of already emitted instructions, used to share identical subexpressions.
+    registers: HashMap<Instruction, usize>,
+}
+
+impl Compiler<'_> {
+    /// Recursively compiles an expression and returns the register holding its value.
+    fn compile(&mut self, expression: &Expression) -> usize {
+        let instruction = match expression {
+            Expression::Constant(value) => Instruction::Constant(*value),
+            Expression::Identifier(iden) => {
+                Instruction::Variable(self.variables.binary_search(iden).unwrap())
+            }
+            Expression::Not(expr) => Instruction::Not(self.compile(expr)),
+            Expression::And(left, right) => {
+                Instruction::And(self.compile(left), self.compile(right))
+            }
+            Expression::Nand(left, right) => {
+                Instruction::Nand(self.compile(left), self.compile(right))
+            }
+            Expression::Or(left, right) => Instruction::Or(self.compile(left), self.compile(right)),
+            Expression::Nor(left, right) => {
+                Instruction::Nor(self.compile(left), self.compile(right))
+            }
+            Expression::Xor(left, right) => {
+                Instruction::Xor(self.compile(left), self.compile(right))
+            }
+            Expression::Xnor(left, right) => {
+                Instruction::Xnor(self.compile(left), self.compile(right))
+            }
+            Expression::Implies(left, right) => {
+                Instruction::Implies(self.compile(left), self.compile(right))
+            }
+        };
+
+        *self.registers.entry(instruction).or_insert_with(|| {
+            self.instructions.push(instruction);
+            self.instructions.len() - 1
+        })
+    }
+}
+
+impl Expression {
+    /// Compiles the expression into a bit-parallel [Program].
+    pub fn compile(&self) -> Program {
+        Program::compile(self)
+    }
+}
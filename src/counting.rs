@@ -0,0 +1,213 @@
+use crate::ast::Expression;
+use crate::cnf::Cnf;
+use crate::literal::Variable;
+use num_bigint::BigUint;
+use std::collections::{BTreeSet, HashMap};
+
+/// A literal of the counter: the index of its variable times two, plus one if it is negated.
+type Lit = usize;
+
+impl Cnf {
+    /// Counts the assignments of the variables of the formula that satisfy all clauses.
+    ///
+    /// The count is exact and does not enumerate assignments: after unit propagation the clauses are split into
+    /// components without common variables, whose counts multiply, and every component is counted by branching on its
+    /// most frequent variable. Counts of components are cached, so a component reached by several branches is only
+    /// counted once.
+    ///
+    /// # Returns
+    /// The number of models over [Cnf::variables].
+    pub fn count_models(&self) -> BigUint {
+        let variables = self.variables();
+        let clauses = self
+            .clauses
+            .iter()
+            .filter(|clause| !clause.is_tautology())
+            .map(|clause| {
+                let mut literals: Vec<Lit> = clause
+                    .literals
+                    .iter()
+                    .map(|literal| {
+                        let index = variables.binary_search(&literal.variable).unwrap();
+                        2 * index + usize::from(!literal.positive)
+                    })
+                    .collect();
+                literals.sort();
+                literals
+            })
+            .collect();
+
+        ModelCounter::default().count(clauses, variables.len())
+    }
+}
+
+impl Expression {
+    /// Counts the assignments of the variables of the expression that make it `1`.
+    ///
+    /// Unlike [Expression::satisfying_count], this does not enumerate all `2^n` assignments and is not limited by the
+    /// size of `usize`. The models of the Tseitin encoding are counted, since every model of the expression extends to
+    /// exactly one of them, and variables removed by constant folding double the count.
+    pub fn count_models(&self) -> BigUint {
+        let cnf = self.to_tseitin_cnf();
+        let encoded = cnf
+            .variables()
+            .iter()
+            .filter(|variable| matches!(variable, Variable::Input(_)))
+            .count();
+
+        cnf.count_models() << (self.variables().len() - encoded)
+    }
+}
+
+/// Helper state of the model counter.
+#[derive(Default)]
+struct ModelCounter {
+    /// Counts of already counted components, keyed by their sorted clauses.
+    cache: HashMap<Vec<Vec<Lit>>, BigUint>,
+}
+
+impl ModelCounter {
+    /// Counts the models of clauses over a number of unassigned variables, including variables without clauses.
+    fn count(&mut self, clauses: Vec<Vec<Lit>>, variables_length: usize) -> BigUint {
+        let Some((clauses, assigned)) = propagate(clauses) else {
+            return BigUint::ZERO;
+        };
+
+        let mentioned: BTreeSet<usize> = clauses
+            .iter()
+            .flatten()
+            .map(|&literal| literal >> 1)
+            .collect();
+        let mut total = BigUint::from(1u8) << (variables_length - assigned - mentioned.len());
+
+        for component in components(clauses) {
+            total *= self.count_component(component);
+            if total == BigUint::ZERO {
+                break;
+            }
+        }
+
+        total
+    }
+
+    /// Counts the models of a connected component over the variables of its clauses.
+    fn count_component(&mut self, mut clauses: Vec<Vec<Lit>>) -> BigUint {
+        clauses.sort();
+        if let Some(count) = self.cache.get(&clauses) {
+            return count.clone();
+        }
+
+        let mut occurrences: HashMap<usize, usize> = HashMap::new();
+        for &literal in clauses.iter().flatten() {
+            *occurrences.entry(literal >> 1).or_default() += 1;
+        }
+        let (&branch, _) = occurrences
+            .iter()
+            .max_by_key(|&(&variable, &count)| (count, std::cmp::Reverse(variable)))
+            .unwrap();
+
+        let mut count = BigUint::ZERO;
+        for literal in [2 * branch, 2 * branch + 1] {
+            if let Some(reduced) = assign(&clauses, &[literal]) {
+                count += self.count(reduced, occurrences.len() - 1);
+            }
+        }
+
+        self.cache.insert(clauses, count.clone());
+        count
+    }
+}
+
+/// Makes literals true, removing satisfied clauses and the complementary literals from the others.
+///
+/// # Arguments
+/// * `literals` - Sorted literals without complementary pairs.
+///
+/// # Returns
+/// The reduced clauses, or `None` if a clause became empty.
+fn assign(clauses: &[Vec<Lit>], literals: &[Lit]) -> Option<Vec<Vec<Lit>>> {
+    let mut reduced = Vec::with_capacity(clauses.len());
+    for clause in clauses {
+        if clause
+            .iter()
+            .any(|literal| literals.binary_search(literal).is_ok())
+        {
+            continue;
+        }
+
+        let remaining: Vec<Lit> = clause
+            .iter()
+            .copied()
+            .filter(|&literal| literals.binary_search(&(literal ^ 1)).is_err())
+            .collect();
+        if remaining.is_empty() {
+            return None;
+        }
+        reduced.push(remaining);
+    }
+
+    Some(reduced)
+}
+
+/// Assigns the literals of unit clauses, all current units at once, until there are none left.
+///
+/// # Returns
+/// The reduced clauses and the number of assigned variables, or `None` if a clause became empty or two units conflict.
+fn propagate(mut clauses: Vec<Vec<Lit>>) -> Option<(Vec<Vec<Lit>>, usize)> {
+    if clauses.iter().any(Vec::is_empty) {
+        return None;
+    }
+
+    let mut assigned = 0;
+    loop {
+        let mut units: Vec<Lit> = clauses
+            .iter()
+            .filter(|clause| clause.len() == 1)
+            .map(|clause| clause[0])
+            .collect();
+        if units.is_empty() {
+            return Some((clauses, assigned));
+        }
+
+        units.sort();
+        units.dedup();
+        if units.windows(2).any(|pair| pair[0] ^ 1 == pair[1]) {
+            return None;
+        }
+
+        clauses = assign(&clauses, &units)?;
+        assigned += units.len();
+    }
+}
+
+/// Splits clauses into groups such that clauses of different groups have no common variables.
+fn components(clauses: Vec<Vec<Lit>>) -> Vec<Vec<Vec<Lit>>> {
+    let mut parents: HashMap<usize, usize> = HashMap::new();
+    for clause in &clauses {
+        let first = find_root(&mut parents, clause[0] >> 1);
+        for &literal in &clause[1..] {
+            let other = find_root(&mut parents, literal >> 1);
+            parents.insert(other, first);
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<Vec<Lit>>> = HashMap::new();
+    for clause in clauses {
+        let group = find_root(&mut parents, clause[0] >> 1);
+        groups.entry(group).or_default().push(clause);
+    }
+
+    groups.into_values().collect()
+}
+
+/// Finds the representative of the group of a variable, compressing the path to it.
+fn find_root(parents: &mut HashMap<usize, usize>, variable: usize) -> usize {
+    let parent = *parents.entry(variable).or_insert(variable);
+    if parent == variable {
+        return variable;
+    }
+
+    let root = find_root(parents, parent);
+    parents.insert(variable, root);
+    root
+}
@@ -0,0 +1,132 @@
+use crate::ast::Expression;
+use crate::dnf::Term;
+use crate::literal::{Literal, Variable};
+
+/// A product term over indexed variables, represented as a pair of bitmasks.
+///
+/// Bit `i` refers to the `i`-th variable, matching the row numbering of [crate::truth_table::TruthTable].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cube {
+    /// Values of the fixed variables. Bits outside of `mask` are always zero.
+    pub values: u64,
+    /// Variables that appear in the term; the others are free (`-`).
+    pub mask: u64,
+}
+
+impl Cube {
+    /// Creates a cube from values and a mask, clearing value bits outside of the mask.
+    pub fn new(values: u64, mask: u64) -> Self {
+        Self {
+            values: values & mask,
+            mask,
+        }
+    }
+
+    /// Creates a cube that contains a single minterm.
+    ///
+    /// # Arguments
+    /// * `minterm` - The row number.
+    /// * `variables_length` - The number of variables.
+    pub fn minterm(minterm: usize, variables_length: usize) -> Self {
+        Self::new(minterm as u64, full_mask(variables_length))
+    }
+
+    /// Creates the cube that contains every minterm.
+    pub fn universe() -> Self {
+        Self { values: 0, mask: 0 }
+    }
+
+    /// Returns the number of literals of the term.
+    pub fn literal_count(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    /// Checks whether the cube contains a minterm.
+    pub fn contains_minterm(&self, minterm: usize) -> bool {
+        minterm as u64 & self.mask == self.values
+    }
+
+    /// Checks whether every minterm of `other` is in this cube.
+    pub fn contains(&self, other: &Cube) -> bool {
+        other.mask & self.mask == self.mask && other.values & self.mask == self.values
+    }
+
+    /// Checks whether the cubes have at least one minterm in common.
+    pub fn intersects(&self, other: &Cube) -> bool {
+        (self.values ^ other.values) & self.mask & other.mask == 0
+    }
+
+    /// Merges two cubes that fix the same variables and differ in exactly one value.
+    ///
+    /// # Returns
+    /// The merged cube with the differing variable freed, or `None` if the cubes cannot be merged.
+    pub fn combine(&self, other: &Cube) -> Option<Cube> {
+        let difference = self.values ^ other.values;
+        if self.mask == other.mask && difference.count_ones() == 1 {
+            Some(Cube::new(self.values, self.mask & !difference))
+        } else {
+            None
+        }
+    }
+
+    /// Enumerates the minterms of the cube.
+    ///
+    /// # Arguments
+    /// * `variables_length` - The number of variables.
+    pub fn minterms(&self, variables_length: usize) -> impl Iterator<Item = usize> + '_ {
+        let free = full_mask(variables_length) & !self.mask;
+        let mut subset = Some(0u64);
+
+        std::iter::from_fn(move || {
+            let current = subset?;
+            subset = match current.wrapping_sub(free) & free {
+                0 => None,
+                next => Some(next),
+            };
+            Some((self.values | current) as usize)
+        })
+    }
+
+    /// Formats the cube as a pattern of `0`, `1` and `-`, one character per variable starting with the first one.
+    pub fn to_pattern(&self, variables_length: usize) -> String {
+        (0..variables_length)
+            .map(|index| {
+                if self.mask >> index & 1 == 0 {
+                    '-'
+                } else if self.values >> index & 1 == 1 {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect()
+    }
+
+    /// Converts the cube into a [Term] over the given variables.
+    pub fn to_term(&self, variables: &[char]) -> Term {
+        Term::new(
+            variables
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| self.mask >> index & 1 == 1)
+                .map(|(index, &variable)| Literal {
+                    variable: Variable::Input(variable),
+                    positive: self.values >> index & 1 == 1,
+                }),
+        )
+    }
+
+    /// Converts the cube into an [Expression] over the given variables.
+    pub fn to_expression(&self, variables: &[char]) -> Expression {
+        self.to_term(variables).to_expression()
+    }
+}
+
+/// Returns a mask with the lowest `variables_length` bits set.
+pub fn full_mask(variables_length: usize) -> u64 {
+    if variables_length >= u64::BITS as usize {
+        u64::MAX
+    } else {
+        (1 << variables_length) - 1
+    }
+}
@@ -0,0 +1,142 @@
+use crate::ast::Expression;
+use crate::literal::{Literal, Variable};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+/// A conjunction of literals, i.e. a product term.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Term {
+    /// Sorted, unique literals of the term. An empty term is always true.
+    pub literals: Vec<Literal>,
+}
+
+/// A formula in disjunctive normal form, i.e. a sum of products.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dnf {
+    /// The terms of the formula. A formula without terms is always false.
+    pub terms: Vec<Term>,
+}
+
+impl Term {
+    /// Creates a [Term] from literals, sorting them and removing duplicates.
+    pub fn new(literals: impl IntoIterator<Item = Literal>) -> Self {
+        let literals: BTreeSet<Literal> = literals.into_iter().collect();
+        Self {
+            literals: literals.into_iter().collect(),
+        }
+    }
+
+    /// Checks whether every literal of this term is also in `other`, making `other` redundant next to this term.
+    pub fn subsumes(&self, other: &Term) -> bool {
+        self.literals.len() <= other.literals.len()
+            && self
+                .literals
+                .iter()
+                .all(|literal| other.literals.binary_search(literal).is_ok())
+    }
+
+    /// Evaluates the term with given variables; missing variables are treated as `false`.
+    pub fn evaluate(&self, variables: &HashMap<char, bool>) -> bool {
+        self.literals.iter().all(|literal| match literal.variable {
+            Variable::Input(iden) => {
+                literal.evaluate(variables.get(&iden).copied().unwrap_or(false))
+            }
+            Variable::Auxiliary(_) => false,
+        })
+    }
+
+    /// Converts the term into an [Expression] chaining its literals with AND.
+    pub fn to_expression(&self) -> Expression {
+        self.literals
+            .iter()
+            .map(|literal| literal_expression(*literal))
+            .reduce(|left, right| Expression::And(Box::new(left), Box::new(right)))
+            .unwrap_or(Expression::Constant(true))
+    }
+}
+
+impl Dnf {
+    /// Evaluates the formula with given variables; missing variables are treated as `false`.
+    pub fn evaluate(&self, variables: &HashMap<char, bool>) -> bool {
+        self.terms.iter().any(|term| term.evaluate(variables))
+    }
+
+    /// Converts the formula into an [Expression] chaining its terms with OR.
+    pub fn to_expression(&self) -> Expression {
+        self.terms
+            .iter()
+            .map(Term::to_expression)
+            .reduce(|left, right| Expression::Or(Box::new(left), Box::new(right)))
+            .unwrap_or(Expression::Constant(false))
+    }
+}
+
+impl Display for Term {
+    /// Formats a [Term] as a conjunction of literals.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.literals.is_empty() {
+            return write!(f, "1");
+        }
+
+        for (index, literal) in self.literals.iter().enumerate() {
+            if index > 0 {
+                write!(f, " AND ")?;
+            }
+            write!(f, "{}", literal)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Dnf {
+    /// Formats a [Dnf] as a sum of products, which can be parsed back since AND binds tighter than OR.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (index, term) in self.terms.iter().enumerate() {
+            if index > 0 {
+                write!(f, " OR ")?;
+            }
+            write!(f, "{}", term)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Expression {
+    /// Converts the expression into an equivalent DNF.
+    ///
+    /// The DNF is obtained as the dual of the distributive CNF of the negated expression, so contradictory,
+    /// duplicate and subsumed terms are removed the same way as clauses in [Expression::to_cnf].
+    ///
+    /// # Returns
+    /// A [Dnf] over the input variables.
+    pub fn to_dnf(&self) -> Dnf {
+        let complement = Expression::Not(Box::new(self.clone())).to_cnf();
+        let terms = complement
+            .clauses
+            .into_iter()
+            .map(|clause| Term::new(clause.literals.into_iter().map(Literal::negated)))
+            .collect();
+
+        Dnf { terms }
+    }
+}
+
+/// Converts a literal over an input variable into an [Expression].
+fn literal_expression(literal: Literal) -> Expression {
+    let identifier = match literal.variable {
+        Variable::Input(iden) => Expression::Identifier(iden),
+        Variable::Auxiliary(_) => unreachable!(),
+    };
+
+    if literal.positive {
+        identifier
+    } else {
+        Expression::Not(Box::new(identifier))
+    }
+}
@@ -0,0 +1,91 @@
+use crate::assignment::BitmaskAssignment;
+use crate::ast::Expression;
+use crate::quine_mccluskey::Minimization;
+use crate::truth_table::{TruthTable, TruthTableRow};
+use thiserror::Error;
+
+/// Custom don't-care error created using the [thiserror] crate.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DontCareError {
+    /// Error for a don't-care minterm that is not a row of the table.
+    #[error("Don't-care minterm {minterm} is out of range for a table with {rows_length} rows")]
+    MintermOutOfRange { minterm: usize, rows_length: usize },
+}
+
+impl TruthTable {
+    /// Creates a table of an expression where rows satisfying a don't-care condition are marked as don't-cares.
+    ///
+    /// # Arguments
+    /// * `expression` - The expression specifying the output of care rows.
+    /// * `dont_care` - An expression that is `1` for input combinations where the output does not matter.
+    ///
+    /// # Returns
+    /// A [TruthTable] over the variables of both expressions.
+    pub fn with_dont_care_condition(expression: &Expression, dont_care: &Expression) -> Self {
+        let mut variables = expression.variables();
+        variables.extend(dont_care.variables());
+        variables.sort();
+        variables.dedup();
+
+        let rows = (0..1u64 << variables.len())
+            .map(|mask| {
+                let assignment = BitmaskAssignment::new(&variables, mask);
+                TruthTableRow::new(
+                    (0..variables.len())
+                        .map(|index| mask >> index & 1 == 1)
+                        .collect(),
+                    expression.evaluate_lenient(&assignment),
+                )
+                .with_dont_care(dont_care.evaluate_lenient(&assignment))
+            })
+            .collect();
+
+        Self { variables, rows }
+    }
+
+    /// Marks rows as don't-cares.
+    ///
+    /// # Arguments
+    /// * `minterms` - Row numbers where the output does not matter.
+    ///
+    /// # Errors
+    /// Returns [DontCareError::MintermOutOfRange] if a row number is not a row of the table.
+    pub fn with_dont_cares(mut self, minterms: &[usize]) -> Result<Self, DontCareError> {
+        let rows_length = self.rows.len();
+        for &minterm in minterms {
+            let row = self
+                .rows
+                .get_mut(minterm)
+                .ok_or(DontCareError::MintermOutOfRange {
+                    minterm,
+                    rows_length,
+                })?;
+            row.dont_care = true;
+        }
+
+        Ok(self)
+    }
+
+    /// Collects the row numbers of don't-care rows.
+    ///
+    /// # Returns
+    /// A sorted `Vec<usize>` of don't-care row numbers.
+    pub fn dont_cares(&self) -> Vec<usize> {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| row.dont_care)
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+impl Expression {
+    /// Minimizes the expression with the Quine–McCluskey method, using another expression as the don't-care set.
+    ///
+    /// # Arguments
+    /// * `dont_care` - An expression that is `1` for input combinations where the result does not matter.
+    pub fn minimize_with_dont_cares(&self, dont_care: &Expression) -> Minimization {
+        TruthTable::with_dont_care_condition(self, dont_care).minimize()
+    }
+}
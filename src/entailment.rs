@@ -0,0 +1,91 @@
+use crate::ast::Expression;
+use crate::parser::Rule;
+use crate::sat::{Model, Solver};
+use pest::iterators::Pair;
+
+/// An argument from premises to a conclusion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Argument {
+    /// The premises, assumed to be `1`.
+    pub premises: Vec<Expression>,
+    /// The conclusion that should follow from the premises.
+    pub conclusion: Expression,
+}
+
+impl Argument {
+    /// Creates an [Argument] from result of Pest parsing.
+    ///
+    /// # Arguments
+    /// * `pair` - A `Pair<Rule>` of the `argument` rule.
+    pub fn ast(pair: Pair<Rule>) -> Self {
+        let (premises, conclusion) = argument_parts(pair);
+
+        Self {
+            premises: premises.into_iter().map(Expression::ast).collect(),
+            conclusion: Expression::ast(conclusion),
+        }
+    }
+
+    /// Returns the sources of the premises and of the conclusion of a parsed argument.
+    ///
+    /// # Arguments
+    /// * `pair` - A `Pair<Rule>` of the `argument` rule.
+    pub fn sources<'i>(pair: &Pair<'i, Rule>) -> (Vec<&'i str>, &'i str) {
+        let (premises, conclusion) = argument_parts(pair.clone());
+
+        (
+            premises.iter().map(Pair::as_str).collect(),
+            conclusion.as_str(),
+        )
+    }
+
+    /// Checks whether the premises entail the conclusion.
+    pub fn is_valid(&self) -> bool {
+        Expression::entails(&self.premises, &self.conclusion)
+    }
+
+    /// Finds an assignment making all premises `1` and the conclusion `0`.
+    pub fn counterexample(&self) -> Option<Model> {
+        Expression::entailment_counterexample(&self.premises, &self.conclusion)
+    }
+}
+
+impl Expression {
+    /// Checks whether premises entail a conclusion, i.e. every assignment making all premises `1` makes the conclusion `1`.
+    ///
+    /// Without premises, this checks whether the conclusion is a tautology.
+    pub fn entails(premises: &[Expression], conclusion: &Expression) -> bool {
+        Expression::entailment_counterexample(premises, conclusion).is_none()
+    }
+
+    /// Finds an assignment making all premises `1` and a conclusion `0` with the SAT solver.
+    ///
+    /// # Returns
+    /// A [Model] of the variables of the premises and the conclusion, or `None` if the premises entail the conclusion.
+    pub fn entailment_counterexample(
+        premises: &[Expression],
+        conclusion: &Expression,
+    ) -> Option<Model> {
+        let mut solver = Solver::new();
+        for premise in premises {
+            solver.add_expression(premise);
+        }
+        solver.add_expression(&Expression::Not(Box::new(conclusion.clone())));
+
+        solver.solve()
+    }
+}
+
+/// Splits a pair of the `argument` rule into the expression pairs of its premises and of its conclusion.
+fn argument_parts(pair: Pair<Rule>) -> (Vec<Pair<Rule>>, Pair<Rule>) {
+    let mut inner = pair.into_inner();
+    let premises = inner
+        .next()
+        .unwrap()
+        .into_inner()
+        .filter(|pair| pair.as_rule() == Rule::expression)
+        .collect();
+    let conclusion = inner.next().unwrap().into_inner().nth(1).unwrap();
+
+    (premises, conclusion)
+}
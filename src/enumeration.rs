@@ -0,0 +1,111 @@
+use crate::ast::Expression;
+use crate::cnf::{Clause, Cnf};
+use crate::literal::{Literal, Variable};
+use crate::sat::{Model, Solver};
+
+/// A lazy iterator over the satisfying assignments of an expression, projected onto a set of variables.
+///
+/// Every model is found by the incremental [Solver] and then excluded by a blocking clause over the projected variables,
+/// so each projected assignment is yielded exactly once and no truth table is built. The order of the models depends
+/// on the search of the solver.
+#[derive(Debug, Clone)]
+pub struct Models {
+    /// The solver holding the expression and the blocking clauses of all yielded models.
+    solver: Solver,
+    /// A sorted list of unique variables the models are projected onto.
+    variables: Vec<char>,
+    /// Values of variables assumed for every search.
+    assumptions: Vec<(char, bool)>,
+    /// Set once there are no models left.
+    exhausted: bool,
+}
+
+impl Models {
+    /// Creates an iterator over the assignments of variables that extend to a satisfying assignment of an expression.
+    ///
+    /// # Arguments
+    /// * `expression` - The expression whose models are enumerated.
+    /// * `variables` - The variables to project onto; variables not in the expression take both values.
+    pub fn new(expression: &Expression, variables: &[char]) -> Self {
+        let mut variables = variables.to_vec();
+        variables.sort();
+        variables.dedup();
+
+        let mut solver = Solver::new();
+        solver.add_expression(expression);
+        for &variable in &variables {
+            solver.declare(variable);
+        }
+
+        Self {
+            solver,
+            variables,
+            assumptions: Vec::new(),
+            exhausted: false,
+        }
+    }
+
+    /// Restricts the enumeration to models in which the assumed variables have the given values.
+    ///
+    /// # Arguments
+    /// * `assumptions` - Pairs of variables and their assumed values.
+    pub fn with_assumptions(mut self, assumptions: &[(char, bool)]) -> Self {
+        self.assumptions = assumptions.to_vec();
+        self
+    }
+}
+
+impl Iterator for Models {
+    type Item = Model;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let Some(model) = self.solver.solve_with_assumptions(&self.assumptions) else {
+            self.exhausted = true;
+            return None;
+        };
+
+        let values: Vec<bool> = self
+            .variables
+            .iter()
+            .map(|&variable| model.values[model.variables.binary_search(&variable).unwrap()])
+            .collect();
+        let blocking = Clause::new(self.variables.iter().zip(&values).map(
+            |(&variable, &value)| Literal {
+                variable: Variable::Input(variable),
+                positive: !value,
+            },
+        ));
+        self.solver.add_cnf(&Cnf {
+            clauses: vec![blocking],
+        });
+
+        Some(Model {
+            variables: self.variables.clone(),
+            values,
+        })
+    }
+}
+
+impl Expression {
+    /// Returns a lazy iterator over the assignments of the variables of the expression that make it `1`.
+    pub fn models(&self) -> Models {
+        Models::new(self, &self.variables())
+    }
+
+    /// Returns a lazy iterator over the assignments of the variables of the expression that make it `0`.
+    pub fn countermodels(&self) -> Models {
+        Models::new(&Expression::Not(Box::new(self.clone())), &self.variables())
+    }
+
+    /// Returns a lazy iterator over the distinct assignments of some variables that extend to a model of the expression.
+    ///
+    /// # Arguments
+    /// * `variables` - The variables to project onto.
+    pub fn projected_models(&self, variables: &[char]) -> Models {
+        Models::new(self, variables)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::assignment::SliceAssignment;
+use crate::ast::Expression;
+use crate::compiled::{BLOCK_SIZE, Program};
+use std::fmt::{Display, Formatter};
+
+/// An assignment on which two expressions evaluate differently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// A sorted list of the variables of both expressions.
+    pub variables: Vec<char>,
+    /// The value of each variable.
+    pub values: Vec<bool>,
+    /// The value of the first expression.
+    pub left: bool,
+    /// The value of the second expression.
+    pub right: bool,
+}
+
+impl Display for Counterexample {
+    /// Formats the assignment of a [Counterexample] as `A = 1, B = 0`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.variables.is_empty() {
+            return write!(f, "no variables");
+        }
+
+        let assignments: Vec<String> = self
+            .variables
+            .iter()
+            .zip(&self.values)
+            .map(|(variable, &value)| format!("{} = {}", variable, u8::from(value)))
+            .collect();
+        write!(f, "{}", assignments.join(", "))
+    }
+}
+
+impl Expression {
+    /// Checks whether two expressions have the same value for every assignment of the union of their variables.
+    pub fn equivalent_to(&self, other: &Expression) -> bool {
+        self.counterexample(other).is_none()
+    }
+
+    /// Finds an assignment distinguishing two expressions.
+    ///
+    /// Their XOR is compiled over the union of their variables and evaluated 64 rows at a time. Among all distinguishing
+    /// assignments, the one setting the fewest variables to `1` is returned, and ties go to the lowest row number.
+    ///
+    /// # Returns
+    /// A [Counterexample], or `None` if the expressions are equivalent.
+    pub fn counterexample(&self, other: &Expression) -> Option<Counterexample> {
+        let difference = Expression::Xor(Box::new(self.clone()), Box::new(other.clone()));
+        let program = Program::compile(&difference);
+
+        let row = program
+            .evaluate_all()
+            .iter()
+            .enumerate()
+            .flat_map(|(block, &results)| {
+                (0..BLOCK_SIZE)
+                    .filter(move |bit| results >> bit & 1 == 1)
+                    .map(move |bit| block * BLOCK_SIZE + bit)
+            })
+            .min_by_key(|&row| (row.count_ones(), row))?;
+
+        let values: Vec<bool> = (0..program.variables.len())
+            .map(|index| row >> index & 1 == 1)
+            .collect();
+        let left = self.evaluate_lenient(&SliceAssignment::new(&program.variables, &values));
+
+        Some(Counterexample {
+            variables: program.variables,
+            values,
+            left,
+            right: !left,
+        })
+    }
+}
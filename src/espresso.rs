@@ -0,0 +1,338 @@
+use crate::ast::Expression;
+use crate::cube::Cube;
+use crate::dnf::Dnf;
+use crate::literal::Variable;
+use crate::truth_table::TruthTable;
+
+/// A sum of products over indexed variables, as produced by heuristic minimization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cover {
+    /// A sorted list of unique variables; bit `i` of every cube refers to the variable with index `i`.
+    pub variables: Vec<char>,
+    /// The product terms of the cover.
+    pub cubes: Vec<Cube>,
+}
+
+impl Cover {
+    /// Converts the cover into a [Dnf].
+    pub fn to_dnf(&self) -> Dnf {
+        Dnf {
+            terms: self
+                .cubes
+                .iter()
+                .map(|cube| cube.to_term(&self.variables))
+                .collect(),
+        }
+    }
+
+    /// Converts the cover into an [Expression].
+    pub fn to_expression(&self) -> Expression {
+        self.to_dnf().to_expression()
+    }
+
+    /// Returns the total number of literals of the cover.
+    pub fn literal_count(&self) -> usize {
+        self.cubes.iter().map(Cube::literal_count).sum()
+    }
+}
+
+/// Minimizes a function given as cube covers with an Espresso-style heuristic.
+///
+/// The OFF-set is computed by complementing the ON-set and the don't-care set, then the cover is improved by repeating
+/// the expand, irredundant and reduce steps until its cost (number of cubes, then literals) stops decreasing.
+/// No truth table is built, so functions with dozens of inputs can be minimized, but the result is not guaranteed to be minimal.
+///
+/// # Arguments
+/// * `variables` - The variables of the function; bit `i` of a cube refers to `variables[i]`.
+/// * `on_set` - Cubes where the function is `1`.
+/// * `dont_care_set` - Cubes where the value of the function does not matter.
+///
+/// # Returns
+/// A [Cover] made of prime implicants, none of which is redundant.
+pub fn minimize(variables: &[char], on_set: &[Cube], dont_care_set: &[Cube]) -> Cover {
+    let variables_length = variables.len();
+    let care_set: Vec<Cube> = on_set.iter().chain(dont_care_set).copied().collect();
+    let off_set = complement(&care_set, variables_length);
+
+    let mut cover = expand(on_set.to_vec(), &off_set);
+    cover = irredundant(cover, dont_care_set, variables_length);
+
+    loop {
+        let previous = cover.clone();
+
+        cover = reduce(cover, dont_care_set, variables_length);
+        cover = expand(cover, &off_set);
+        cover = irredundant(cover, dont_care_set, variables_length);
+
+        if cost(&cover) >= cost(&previous) {
+            if cost(&cover) > cost(&previous) {
+                cover = previous;
+            }
+            break;
+        }
+    }
+
+    cover.sort();
+    Cover {
+        variables: variables.to_vec(),
+        cubes: cover,
+    }
+}
+
+/// Returns the cost of a cover as the number of cubes and then the number of literals.
+fn cost(cover: &[Cube]) -> (usize, usize) {
+    (cover.len(), cover.iter().map(Cube::literal_count).sum())
+}
+
+/// Expands every cube into a prime implicant by freeing literals while it does not intersect the OFF-set.
+///
+/// Larger cubes are expanded first, and cubes contained in an already expanded cube are dropped.
+fn expand(mut cover: Vec<Cube>, off_set: &[Cube]) -> Vec<Cube> {
+    cover.sort_by_key(Cube::literal_count);
+    let mut expanded: Vec<Cube> = Vec::with_capacity(cover.len());
+
+    for cube in &cover {
+        if expanded.iter().any(|other| other.contains(cube)) {
+            continue;
+        }
+
+        let mut bits: Vec<u64> = (0..u64::BITS)
+            .map(|index| 1 << index)
+            .filter(|bit| cube.mask & bit != 0)
+            .collect();
+        bits.sort_by_key(|bit| cover.iter().filter(|other| other.mask & bit != 0).count());
+
+        let mut prime = *cube;
+        for bit in bits {
+            let candidate = Cube::new(prime.values, prime.mask & !bit);
+            if off_set.iter().all(|off| !off.intersects(&candidate)) {
+                prime = candidate;
+            }
+        }
+
+        expanded.retain(|other| !prime.contains(other));
+        expanded.push(prime);
+    }
+
+    expanded
+}
+
+/// Removes cubes that are covered by the other cubes together with the don't-care set.
+///
+/// Smaller cubes are considered for removal first.
+fn irredundant(mut cover: Vec<Cube>, dont_care_set: &[Cube], variables_length: usize) -> Vec<Cube> {
+    cover.sort_by_key(|cube| std::cmp::Reverse(cube.literal_count()));
+
+    let mut index = 0;
+    while index < cover.len() {
+        let rest: Vec<Cube> = cover
+            .iter()
+            .enumerate()
+            .filter(|&(other, _)| other != index)
+            .map(|(_, cube)| *cube)
+            .chain(dont_care_set.iter().copied())
+            .collect();
+
+        if is_contained(&cover[index], &rest, variables_length) {
+            cover.remove(index);
+        } else {
+            index += 1;
+        }
+    }
+
+    cover
+}
+
+/// Shrinks every cube to the smallest cube containing the minterms that only it covers.
+///
+/// Reduced cubes can later be expanded in a different direction, which lets the loop escape local minima.
+fn reduce(mut cover: Vec<Cube>, dont_care_set: &[Cube], variables_length: usize) -> Vec<Cube> {
+    cover.sort_by_key(Cube::literal_count);
+
+    let mut index = 0;
+    while index < cover.len() {
+        let cube = cover[index];
+        let rest: Vec<Cube> = cover
+            .iter()
+            .enumerate()
+            .filter(|&(other, _)| other != index)
+            .map(|(_, cube)| *cube)
+            .chain(dont_care_set.iter().copied())
+            .collect();
+
+        let uncovered = complement(&cofactor(&rest, &cube), variables_length);
+        match supercube(&uncovered) {
+            Some(supercube) => {
+                cover[index] =
+                    Cube::new(cube.values | supercube.values, cube.mask | supercube.mask);
+                index += 1;
+            }
+            None => {
+                cover.remove(index);
+            }
+        }
+    }
+
+    cover
+}
+
+/// Returns the smallest cube containing all cubes, or `None` for an empty cover.
+fn supercube(cover: &[Cube]) -> Option<Cube> {
+    let first = cover.first()?;
+    let mask = cover.iter().fold(first.mask, |mask, cube| {
+        mask & cube.mask & !(cube.values ^ first.values)
+    });
+
+    Some(Cube::new(first.values, mask))
+}
+
+/// Checks whether every minterm of `cube` is covered by `cover`.
+fn is_contained(cube: &Cube, cover: &[Cube], variables_length: usize) -> bool {
+    is_tautology(&cofactor(cover, cube), variables_length)
+}
+
+/// Computes the cofactor of a cover with respect to a cube, i.e. the cover restricted to the cube with its variables freed.
+fn cofactor(cover: &[Cube], cube: &Cube) -> Vec<Cube> {
+    cover
+        .iter()
+        .filter(|other| other.intersects(cube))
+        .map(|other| Cube::new(other.values, other.mask & !cube.mask))
+        .collect()
+}
+
+/// Computes the cofactor of a cover with respect to a single literal.
+fn cofactor_literal(cover: &[Cube], bit: u64, value: bool) -> Vec<Cube> {
+    let literal = Cube::new(if value { bit } else { 0 }, bit);
+    cofactor(cover, &literal)
+}
+
+/// Picks the variable fixed in the most cubes, which is the best one to split the cover on.
+fn splitting_bit(cover: &[Cube]) -> u64 {
+    (0..u64::BITS)
+        .map(|index| 1u64 << index)
+        .max_by_key(|bit| cover.iter().filter(|cube| cube.mask & bit != 0).count())
+        .unwrap()
+}
+
+/// Checks whether a cover contains every minterm, by recursive Shannon expansion.
+fn is_tautology(cover: &[Cube], variables_length: usize) -> bool {
+    if cover.iter().any(|cube| cube.mask == 0) {
+        return true;
+    }
+
+    let minterm_count: u128 = cover
+        .iter()
+        .map(|cube| 1u128 << (variables_length - cube.literal_count()))
+        .sum();
+    if minterm_count < 1u128 << variables_length {
+        return false;
+    }
+
+    let bit = splitting_bit(cover);
+    is_tautology(&cofactor_literal(cover, bit, false), variables_length)
+        && is_tautology(&cofactor_literal(cover, bit, true), variables_length)
+}
+
+/// Computes a cover of the minterms not covered by `cover`, by recursive Shannon expansion.
+fn complement(cover: &[Cube], variables_length: usize) -> Vec<Cube> {
+    if cover.is_empty() {
+        return vec![Cube::universe()];
+    }
+    if cover.iter().any(|cube| cube.mask == 0) {
+        return vec![];
+    }
+
+    if let [cube] = cover {
+        let mut fixed = 0;
+        let mut result = Vec::new();
+        for index in 0..variables_length {
+            let bit = 1 << index;
+            if cube.mask & bit != 0 {
+                result.push(Cube::new(cube.values ^ bit, fixed | bit));
+                fixed |= bit;
+            }
+        }
+        return result;
+    }
+
+    let bit = splitting_bit(cover);
+    let negative = complement(&cofactor_literal(cover, bit, false), variables_length);
+    let positive = complement(&cofactor_literal(cover, bit, true), variables_length);
+
+    let mut result = Vec::with_capacity(negative.len() + positive.len());
+    for cube in &negative {
+        if positive.contains(cube) {
+            result.push(*cube);
+        } else {
+            result.push(Cube::new(cube.values, cube.mask | bit));
+        }
+    }
+    for cube in &positive {
+        if !negative.contains(cube) {
+            result.push(Cube::new(cube.values | bit, cube.mask | bit));
+        }
+    }
+
+    result
+}
+
+impl TruthTable {
+    /// Minimizes the function of the table with the Espresso-style heuristic, using its don't-care rows.
+    pub fn minimize_heuristic(&self) -> Cover {
+        let variables_length = self.variables.len();
+        let cubes = |minterms: Vec<usize>| -> Vec<Cube> {
+            minterms
+                .into_iter()
+                .map(|minterm| Cube::minterm(minterm, variables_length))
+                .collect()
+        };
+
+        minimize(
+            &self.variables,
+            &cubes(self.minterms()),
+            &cubes(self.dont_cares()),
+        )
+    }
+}
+
+impl Expression {
+    /// Minimizes the expression with the Espresso-style heuristic, without building a truth table.
+    pub fn minimize_heuristic(&self) -> Cover {
+        self.minimize_heuristic_with_dont_cares(&Expression::Constant(false))
+    }
+
+    /// Minimizes the expression with the Espresso-style heuristic, using another expression as the don't-care set.
+    ///
+    /// # Arguments
+    /// * `dont_care` - An expression that is `1` for input combinations where the result does not matter.
+    pub fn minimize_heuristic_with_dont_cares(&self, dont_care: &Expression) -> Cover {
+        let mut variables = self.variables();
+        variables.extend(dont_care.variables());
+        variables.sort();
+        variables.dedup();
+
+        let on_set = dnf_cubes(&self.to_dnf(), &variables);
+        let dont_care_set = dnf_cubes(&dont_care.to_dnf(), &variables);
+
+        minimize(&variables, &on_set, &dont_care_set)
+    }
+}
+
+/// Converts the terms of a [Dnf] into cubes over the given variables.
+fn dnf_cubes(dnf: &Dnf, variables: &[char]) -> Vec<Cube> {
+    dnf.terms
+        .iter()
+        .map(|term| {
+            term.literals
+                .iter()
+                .fold(Cube::universe(), |cube, literal| match literal.variable {
+                    Variable::Input(iden) => {
+                        let bit = 1 << variables.binary_search(&iden).unwrap();
+                        let value = if literal.positive { bit } else { 0 };
+                        Cube::new(cube.values | value, cube.mask | bit)
+                    }
+                    Variable::Auxiliary(_) => unreachable!(),
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,372 @@
+use crate::cube::Cube;
+use crate::truth_table::{TruthTable, TruthTableRow};
+use std::fmt::{Display, Formatter, Write};
+use thiserror::Error;
+
+/// ANSI background colors used for implicant groups in terminal output.
+const ANSI_COLORS: [&str; 6] = ["41", "42", "43", "44", "45", "46"];
+
+/// Colors used for implicant groups in SVG output.
+const SVG_COLORS: [&str; 6] = [
+    "#e6194b", "#3cb44b", "#4363d8", "#f58231", "#911eb4", "#42d4f4",
+];
+
+/// The size of a cell in SVG output, in pixels.
+const SVG_CELL: usize = 40;
+
+/// Custom Karnaugh map error created using the [thiserror] crate.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum KarnaughMapError {
+    /// Error for functions with too few or too many variables to draw.
+    #[error("Karnaugh maps support 2 to 6 variables, got {0}")]
+    UnsupportedVariableCount(usize),
+}
+
+/// A Karnaugh map of a [TruthTable] with Gray-code ordered axes and the implicant groups of a minimal cover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KarnaughMap {
+    /// A sorted list of unique variables.
+    pub variables: Vec<char>,
+    /// The number of variables on the row axis; they are the first variables, the rest are on the column axis.
+    pub row_variables_length: usize,
+    /// The minterm number of every cell, indexed by row and then by column.
+    pub cells: Vec<Vec<usize>>,
+    /// The value of every cell, indexed by row and then by column.
+    pub values: Vec<Vec<bool>>,
+    /// Whether every cell is a don't-care, indexed by row and then by column.
+    pub dont_cares: Vec<Vec<bool>>,
+    /// The implicant groups, i.e. the cubes of a minimal sum of products.
+    pub groups: Vec<Cube>,
+}
+
+impl KarnaughMap {
+    /// Creates a Karnaugh map of a truth table, grouping cells by a Quine–McCluskey minimal cover.
+    ///
+    /// # Arguments
+    /// * `truth_table` - A truth table with 2 to 6 variables.
+    ///
+    /// # Errors
+    /// Returns [KarnaughMapError::UnsupportedVariableCount] if the table has fewer than 2 or more than 6 variables.
+    pub fn new(truth_table: &TruthTable) -> Result<Self, KarnaughMapError> {
+        let variables_length = truth_table.variables.len();
+        if !(2..=6).contains(&variables_length) {
+            return Err(KarnaughMapError::UnsupportedVariableCount(variables_length));
+        }
+
+        let row_variables_length = variables_length / 2;
+        let column_variables_length = variables_length - row_variables_length;
+
+        let cells: Vec<Vec<usize>> = gray_codes(row_variables_length)
+            .map(|row_code| {
+                gray_codes(column_variables_length)
+                    .map(|column_code| {
+                        axis_minterm(row_code, row_variables_length, 0)
+                            | axis_minterm(
+                                column_code,
+                                column_variables_length,
+                                row_variables_length,
+                            )
+                    })
+                    .collect()
+            })
+            .collect();
+        let cell_flags = |flag: fn(&TruthTableRow) -> bool| -> Vec<Vec<bool>> {
+            cells
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&minterm| flag(&truth_table.rows[minterm]))
+                        .collect()
+                })
+                .collect()
+        };
+        let values = cell_flags(|row| row.result);
+        let dont_cares = cell_flags(|row| row.dont_care);
+
+        Ok(Self {
+            variables: truth_table.variables.clone(),
+            row_variables_length,
+            cells,
+            values,
+            dont_cares,
+            groups: truth_table.minimize().cover,
+        })
+    }
+
+    /// Renders the map with ANSI colors, highlighting every cell with the color of the first group covering it.
+    pub fn to_ansi(&self) -> String {
+        let mut output = String::new();
+        let _ = self.write_grid(&mut output, |cell, text| {
+            match self
+                .groups
+                .iter()
+                .position(|group| group.contains_minterm(cell))
+            {
+                Some(index) => format!(
+                    "\x1b[{};30m{}\x1b[0m",
+                    ANSI_COLORS[index % ANSI_COLORS.len()],
+                    text
+                ),
+                None => text.to_string(),
+            }
+        });
+        let _ = self.write_groups(&mut output, |index, label| {
+            format!(
+                "\x1b[{};30m{}\x1b[0m",
+                ANSI_COLORS[index % ANSI_COLORS.len()],
+                label
+            )
+        });
+
+        output
+    }
+
+    /// Renders the map as an SVG image with groups drawn as rounded rectangles.
+    ///
+    /// Groups that wrap around an edge of the map are drawn as several rectangles.
+    pub fn to_svg(&self) -> String {
+        let rows_length = self.cells.len();
+        let columns_length = self.cells[0].len();
+        let width = (columns_length + 1) * SVG_CELL + SVG_CELL;
+        let height = (rows_length + 1) * SVG_CELL + SVG_CELL;
+        let origin = SVG_CELL + SVG_CELL / 2;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" font-family="monospace" font-size="14">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"  <text x="4" y="16">{}\{}</text>"#,
+            self.row_label(),
+            self.column_label()
+        );
+
+        for (column, code) in gray_codes(self.column_variables_length()).enumerate() {
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+                origin + column * SVG_CELL + SVG_CELL / 2,
+                origin - 8,
+                code_label(code, self.column_variables_length())
+            );
+        }
+
+        for (row, code) in gray_codes(self.row_variables_length).enumerate() {
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{}" y="{}" text-anchor="end">{}</text>"#,
+                origin - 6,
+                origin + row * SVG_CELL + SVG_CELL / 2 + 5,
+                code_label(code, self.row_variables_length)
+            );
+
+            for column in 0..columns_length {
+                let x = origin + column * SVG_CELL;
+                let y = origin + row * SVG_CELL;
+                let _ = writeln!(
+                    svg,
+                    r#"  <rect x="{x}" y="{y}" width="{SVG_CELL}" height="{SVG_CELL}" fill="none" stroke="black"/>"#
+                );
+                let _ = writeln!(
+                    svg,
+                    r#"  <text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+                    x + SVG_CELL / 2,
+                    y + SVG_CELL / 2 + 5,
+                    self.value_char(row, column)
+                );
+            }
+        }
+
+        for (index, group) in self.groups.iter().enumerate() {
+            let inset = 3 + 3 * (index % 4);
+            let color = SVG_COLORS[index % SVG_COLORS.len()];
+
+            for (first_row, last_row) in runs((0..rows_length).map(|row| {
+                (0..columns_length).any(|column| group.contains_minterm(self.cells[row][column]))
+            })) {
+                for (first_column, last_column) in runs((0..columns_length).map(|column| {
+                    (0..rows_length).any(|row| group.contains_minterm(self.cells[row][column]))
+                })) {
+                    let _ = writeln!(
+                        svg,
+                        r#"  <rect x="{}" y="{}" width="{}" height="{}" rx="8" fill="{color}" fill-opacity="0.15" stroke="{color}" stroke-width="2"/>"#,
+                        origin + first_column * SVG_CELL + inset,
+                        origin + first_row * SVG_CELL + inset,
+                        (last_column - first_column + 1) * SVG_CELL - 2 * inset,
+                        (last_row - first_row + 1) * SVG_CELL - 2 * inset,
+                    );
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Returns the number of variables on the column axis.
+    fn column_variables_length(&self) -> usize {
+        self.variables.len() - self.row_variables_length
+    }
+
+    /// Returns the names of the row variables.
+    fn row_label(&self) -> String {
+        self.variables[..self.row_variables_length].iter().collect()
+    }
+
+    /// Returns the names of the column variables.
+    fn column_label(&self) -> String {
+        self.variables[self.row_variables_length..].iter().collect()
+    }
+
+    /// Helper method that writes the axes and cells, decorating each cell with `decorate`.
+    ///
+    /// Every cell shows its value followed by the letters of the groups covering it.
+    fn write_grid(
+        &self,
+        output: &mut impl Write,
+        decorate: impl Fn(usize, &str) -> String,
+    ) -> std::fmt::Result {
+        let column_variables_length = self.column_variables_length();
+        let cell_width = (0..self.cells.len())
+            .flat_map(|row| (0..self.cells[row].len()).map(move |column| (row, column)))
+            .map(|(row, column)| self.cell_text(row, column).len())
+            .max()
+            .unwrap_or(1)
+            .max(column_variables_length)
+            + 1;
+        let label_width = self.row_variables_length.max(self.row_label().len()) + 1;
+
+        writeln!(output, "{:label_width$}{}", "", self.column_label())?;
+        write!(output, "{:label_width$}", self.row_label())?;
+        for code in gray_codes(column_variables_length) {
+            write!(
+                output,
+                "{:>cell_width$}",
+                code_label(code, column_variables_length)
+            )?;
+        }
+        writeln!(output)?;
+
+        for (row, code) in gray_codes(self.row_variables_length).enumerate() {
+            write!(
+                output,
+                "{:<label_width$}",
+                code_label(code, self.row_variables_length)
+            )?;
+            for (column, &cell) in self.cells[row].iter().enumerate() {
+                let text = format!("{:>cell_width$}", self.cell_text(row, column));
+                write!(output, "{}", decorate(cell, &text))?;
+            }
+            writeln!(output)?;
+        }
+
+        Ok(())
+    }
+
+    /// Helper method that writes the legend of groups, decorating each label with `decorate`.
+    fn write_groups(
+        &self,
+        output: &mut impl Write,
+        decorate: impl Fn(usize, &str) -> String,
+    ) -> std::fmt::Result {
+        writeln!(output, "\nGroups:")?;
+        for (index, group) in self.groups.iter().enumerate() {
+            writeln!(
+                output,
+                "  {}  {}  {}",
+                decorate(index, &group_letter(index).to_string()),
+                group.to_pattern(self.variables.len()),
+                group.to_term(&self.variables)
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Helper method that returns `1` or `0` for the value of a cell, or `X` for a don't-care.
+    fn value_char(&self, row: usize, column: usize) -> char {
+        if self.dont_cares[row][column] {
+            'X'
+        } else if self.values[row][column] {
+            '1'
+        } else {
+            '0'
+        }
+    }
+
+    /// Helper method that returns the value of a cell followed by the letters of the groups covering it.
+    fn cell_text(&self, row: usize, column: usize) -> String {
+        let cell = self.cells[row][column];
+
+        std::iter::once(self.value_char(row, column))
+            .chain(
+                self.groups
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, group)| group.contains_minterm(cell))
+                    .map(|(index, _)| group_letter(index)),
+            )
+            .collect()
+    }
+}
+
+impl Display for KarnaughMap {
+    /// Formats a [KarnaughMap] as plain text, marking cells with the letters of the groups covering them.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_grid(f, |_, text| text.to_string())?;
+        self.write_groups(f, |_, label| label.to_string())
+    }
+}
+
+/// Enumerates the reflected Gray code sequence over `length` bits.
+fn gray_codes(length: usize) -> impl Iterator<Item = usize> {
+    (0..1usize << length).map(|index| index ^ (index >> 1))
+}
+
+/// Formats a Gray code with the bit of the first axis variable on the left.
+fn code_label(code: usize, length: usize) -> String {
+    (0..length)
+        .map(|position| {
+            if code >> (length - 1 - position) & 1 == 1 {
+                '1'
+            } else {
+                '0'
+            }
+        })
+        .collect()
+}
+
+/// Converts an axis code into the bits of the corresponding variables in a minterm number.
+///
+/// # Arguments
+/// * `code` - The Gray code of the axis, with the first axis variable as its most significant bit.
+/// * `length` - The number of variables on the axis.
+/// * `offset` - The index of the first axis variable.
+fn axis_minterm(code: usize, length: usize, offset: usize) -> usize {
+    (0..length)
+        .filter(|position| code >> (length - 1 - position) & 1 == 1)
+        .map(|position| 1 << (offset + position))
+        .sum()
+}
+
+/// Returns the letter used to label a group.
+fn group_letter(index: usize) -> char {
+    (b'a' + (index % 26) as u8) as char
+}
+
+/// Splits a sequence of flags into inclusive ranges of consecutive `true` values.
+fn runs(flags: impl Iterator<Item = bool>) -> Vec<(usize, usize)> {
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for (index, flag) in flags.enumerate() {
+        if !flag {
+            continue;
+        }
+        match runs.last_mut() {
+            Some((_, last)) if *last + 1 == index => *last = index,
+            _ => runs.push((index, index)),
+        }
+    }
+    runs
+}
@@ -14,3 +14,138 @@ pub mod parser;
 ///
 /// Defines `TruthTable` and `TruthTableRow`, implements the `Display` trait for both structs and the `From` trait for `TruthTable`.
 pub mod truth_table;
+
+/// # Partial Evaluation Module
+///
+/// Implements partial evaluation of an `Expression` with constant folding and Shannon cofactors.
+pub mod partial_evaluation;
+
+/// # Metrics Module
+///
+/// Defines `Metrics`, `Operator` and `TwoLevelCost`, and implements structural metrics of an `Expression`.
+pub mod metrics;
+
+/// # Support Module
+///
+/// Implements functional support analysis of an `Expression` and a `TruthTable`, detecting vacuous variables.
+pub mod support;
+
+/// # Compiled Evaluator Module
+///
+/// Defines `Program` and `Instruction`, and implements compilation of an `Expression` into a bit-parallel program evaluating 64 rows at a time.
+pub mod compiled;
+
+/// # Assignment Module
+///
+/// Defines the `Assignment` trait with map, slice and bitmask implementations, evaluation errors, and strict and lenient evaluation of an `Expression`.
+pub mod assignment;
+
+/// # Negation Normal Form Module
+///
+/// Implements conversion of an `Expression` into negation normal form.
+pub mod nnf;
+
+/// # Literal Module
+///
+/// Defines `Variable` and `Literal`, the building blocks of normal forms.
+pub mod literal;
+
+/// # Conjunctive Normal Form Module
+///
+/// Defines `Clause` and `Cnf`, and implements distributive and Tseitin conversion of an `Expression` into CNF.
+pub mod cnf;
+
+/// # Disjunctive Normal Form Module
+///
+/// Defines `Term` and `Dnf`, and implements conversion of an `Expression` into a sum of products.
+pub mod dnf;
+
+/// # Canonical Forms Module
+///
+/// Implements conversion of a `TruthTable` into canonical sum of minterms and product of maxterms, and the `Σm`/`ΠM` notation.
+pub mod canonical;
+
+/// # Don't-Care Module
+///
+/// Implements don't-care conditions of a `TruthTable`, given as an `Expression` or as row numbers, and their use in minimization.
+pub mod dont_care;
+
+/// # Cube Module
+///
+/// Defines `Cube`, a bitmask representation of product terms used by two-level minimization.
+pub mod cube;
+
+/// # Quine–McCluskey Module
+///
+/// Defines `Minimization` and implements exact two-level minimization with prime implicant generation and Petrick's method.
+pub mod quine_mccluskey;
+
+/// # Multi-Output Minimization Module
+///
+/// Defines `MultiOutputMinimization` and `PlaCost`, and implements two-level minimization of several outputs sharing product terms.
+pub mod multi_output;
+
+/// # Espresso Module
+///
+/// Defines `Cover` and implements Espresso-style heuristic two-level minimization with don't-care sets.
+pub mod espresso;
+
+/// # Karnaugh Map Module
+///
+/// Defines `KarnaughMap` and implements its plain text, colored terminal and SVG rendering with implicant groups.
+pub mod kmap;
+
+/// # Simplification Module
+///
+/// Defines `Law`, `Step` and `Simplification`, and implements rule-based algebraic simplification of an `Expression` with recorded steps.
+pub mod simplify;
+
+/// # Universal Gates Module
+///
+/// Defines `UniversalGate` and implements conversion of an `Expression` into NAND-only and NOR-only circuits with gate counting.
+pub mod universal;
+
+/// # Algebraic Normal Form Module
+///
+/// Defines `Anf` and implements conversion of an `Expression` and a `TruthTable` into a Zhegalkin polynomial with its algebraic degree.
+pub mod anf;
+
+/// # Classification Module
+///
+/// Defines `Classification` and implements tautology, contradiction and contingency checks with satisfying assignment counts.
+pub mod classification;
+
+/// # Equivalence Module
+///
+/// Defines `Counterexample` and implements equivalence checking of two `Expression`s with minimal distinguishing assignments.
+pub mod equivalence;
+
+/// # SAT Module
+///
+/// Defines `Solver` and `Model`, and implements incremental CDCL satisfiability solving of an `Expression` under assumptions.
+pub mod sat;
+
+/// # Model Counting Module
+///
+/// Implements exact model counting of a `Cnf` and an `Expression` with component decomposition and caching, returning arbitrary-precision counts.
+pub mod counting;
+
+/// # Model Enumeration Module
+///
+/// Defines `Models` and implements lazy enumeration of satisfying and falsifying assignments of an `Expression` with blocking clauses and projection.
+pub mod enumeration;
+
+/// # Entailment Module
+///
+/// Defines `Argument` and implements checking whether premise `Expression`s entail a conclusion, with counterexamples.
+pub mod entailment;
+
+/// # Post Classes Module
+///
+/// Defines `PostClass` and implements membership in Post's five classes and the functional completeness check of a set of connectives.
+pub mod post;
+
+/// # NPN Module
+///
+/// Defines `Symmetry`, `NpnTransform` and `NpnCanonical`, and implements variable symmetry detection and NPN canonical forms of functions.
+pub mod npn;
@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter};
+
+/// A propositional variable of a normal form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Variable {
+    /// A variable of the original expression.
+    Input(char),
+    /// A fresh auxiliary variable introduced by an encoding, numbered from `1`.
+    Auxiliary(usize),
+}
+
+/// A variable or its negation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Literal {
+    /// The variable of the literal.
+    pub variable: Variable,
+    /// `false` if the variable is negated.
+    pub positive: bool,
+}
+
+impl Literal {
+    /// Creates a positive literal.
+    pub fn positive(variable: Variable) -> Self {
+        Self {
+            variable,
+            positive: true,
+        }
+    }
+
+    /// Creates a negative literal.
+    pub fn negative(variable: Variable) -> Self {
+        Self {
+            variable,
+            positive: false,
+        }
+    }
+
+    /// Returns the complementary literal.
+    pub fn negated(self) -> Self {
+        Self {
+            variable: self.variable,
+            positive: !self.positive,
+        }
+    }
+
+    /// Evaluates the literal with the value of its variable.
+    pub fn evaluate(self, value: bool) -> bool {
+        value == self.positive
+    }
+}
+
+impl Display for Variable {
+    /// Formats a [Variable]; auxiliary variables are written as `_1`, `_2`, ...
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Variable::Input(iden) => write!(f, "{}", iden),
+            Variable::Auxiliary(index) => write!(f, "_{}", index),
+        }
+    }
+}
+
+impl Display for Literal {
+    /// Formats a [Literal] using the NOT keyword for negation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.positive {
+            write!(f, "NOT ")?;
+        }
+        write!(f, "{}", self.variable)
+    }
+}
@@ -1,10 +1,28 @@
-use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use anyhow::{Context, Result, bail};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use logical_expression_pest_parser::assignment::format_variables;
 use logical_expression_pest_parser::ast::Expression;
-use logical_expression_pest_parser::parser::{Rule, parse};
+use logical_expression_pest_parser::cube::Cube;
+use logical_expression_pest_parser::entailment::Argument;
+use logical_expression_pest_parser::kmap::KarnaughMap;
+use logical_expression_pest_parser::metrics::Metrics;
+use logical_expression_pest_parser::multi_output::{self, PlaCost};
+use logical_expression_pest_parser::npn::{NpnCanonical, NpnError};
+use logical_expression_pest_parser::parser::{Rule, parse, parse_arguments};
+use logical_expression_pest_parser::post::{self, PostClass};
+use logical_expression_pest_parser::sat::Model;
 use logical_expression_pest_parser::truth_table::TruthTable;
+use logical_expression_pest_parser::universal::UniversalGate;
+use num_bigint::BigUint;
+use pest::iterators::Pair;
+use serde_json::json;
 use std::fs;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// The exit code of a check that does not hold, e.g. of expressions that are not equivalent.
+const CHECK_FAILED: ExitCode = ExitCode::FAILURE;
 
 #[derive(Parser)]
 #[command(
@@ -27,6 +45,90 @@ struct Cli {
     author: bool,
 }
 
+#[derive(Args)]
+struct Input {
+    /// Path to the file for parsing
+    #[arg(short, long, value_name = "FILE", group = "input", required = true)]
+    file: Option<PathBuf>,
+
+    /// Logical expression for parsing
+    #[arg(
+        short,
+        long,
+        value_name = "EXPRESSION",
+        group = "input",
+        required = true
+    )]
+    expression: Option<String>,
+}
+
+#[derive(Args)]
+struct DontCares {
+    /// Comma-separated row numbers whose output does not matter, for an expression given with -e; files use `dontcare` lines
+    #[arg(
+        long,
+        value_name = "ROWS",
+        value_delimiter = ',',
+        conflicts_with = "file"
+    )]
+    dont_care: Vec<usize>,
+}
+
+#[derive(Args)]
+struct Assumptions {
+    /// Comma-separated values assumed for variables while solving, e.g. A=1,B=0
+    #[arg(
+        short,
+        long,
+        value_name = "VALUES",
+        value_delimiter = ',',
+        value_parser = parse_assumption
+    )]
+    assume: Vec<(char, bool)>,
+}
+
+#[derive(Args)]
+struct Listing {
+    /// List up to N models of each satisfiable expression instead of one
+    #[arg(short = 'n', long, value_name = "N")]
+    models: Option<NonZeroUsize>,
+
+    /// Comma-separated variables to project listed models onto
+    #[arg(
+        short,
+        long,
+        value_name = "VARIABLES",
+        value_delimiter = ',',
+        value_parser = parse_variable,
+        requires = "models"
+    )]
+    project: Vec<char>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Method {
+    /// Exact Quine–McCluskey minimization
+    Exact,
+    /// Espresso-style heuristic minimization for large functions
+    Espresso,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MapFormat {
+    /// Plain text with group letters in cells
+    Text,
+    /// Terminal output with colored groups
+    Color,
+    /// SVG image
+    Svg,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Display project information
@@ -34,32 +136,442 @@ enum Commands {
 
     /// Parse a file with logical expressions or string with one logical expression
     Parse {
-        /// Path to the file for parsing
-        #[arg(short, long, value_name = "FILE", group = "input", required = true)]
+        #[command(flatten)]
+        input: Input,
+
+        #[command(flatten)]
+        dont_cares: DontCares,
+
+        /// Boolean flag for showing or not AST
+        #[arg(short, long)]
+        ast: bool,
+
+        /// Show variables the output depends on and vacuous variables
+        #[arg(short, long)]
+        support: bool,
+
+        /// Drop columns of variables that do not affect the output
+        #[arg(long)]
+        drop_vacuous: bool,
+
+        /// Show the expression in negation normal form
+        #[arg(long)]
+        nnf: bool,
+
+        /// Show the expression in disjunctive normal form (sum of products)
+        #[arg(long)]
+        dnf: bool,
+
+        /// Show the expression in algebraic normal form (Zhegalkin polynomial) and its degree
+        #[arg(long)]
+        anf: bool,
+
+        /// Show minterms and maxterms of the truth table in Σm/ΠM notation
+        #[arg(short, long)]
+        canonical: bool,
+
+        /// Print only the classification of each expression instead of its truth table
+        #[arg(long)]
+        classify_only: bool,
+    },
+
+    /// Report complexity metrics of each logical expression
+    Stats {
+        #[command(flatten)]
+        input: Input,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: Format,
+    },
+
+    /// Convert each logical expression into conjunctive normal form
+    Cnf {
+        #[command(flatten)]
+        input: Input,
+
+        /// Use the linear-size Tseitin encoding with auxiliary variables
+        #[arg(short, long)]
+        tseitin: bool,
+
+        /// Print clauses in the DIMACS format
+        #[arg(short, long)]
+        dimacs: bool,
+    },
+
+    /// Minimize each logical expression into a minimal sum of products
+    Minimize {
+        #[command(flatten)]
+        input: Input,
+
+        #[command(flatten)]
+        dont_cares: DontCares,
+
+        /// Minimization method
+        #[arg(short, long, value_enum, default_value = "exact")]
+        method: Method,
+
+        /// Minimize all expressions together as outputs of one circuit, sharing product terms
+        #[arg(long, conflicts_with = "method")]
+        multi_output: bool,
+    },
+
+    /// Draw the Karnaugh map of each logical expression with 2 to 6 variables
+    Kmap {
+        #[command(flatten)]
+        input: Input,
+
+        #[command(flatten)]
+        dont_cares: DontCares,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: MapFormat,
+
+        /// Directory to write SVG files into instead of printing them
+        #[arg(short, long, value_name = "DIR")]
+        output: Option<PathBuf>,
+    },
+
+    /// Simplify each logical expression with the laws of Boolean algebra
+    Simplify {
+        #[command(flatten)]
+        input: Input,
+
+        /// Show every step with the law applied and the rewritten subterm
+        #[arg(short = 'x', long)]
+        explain: bool,
+    },
+
+    /// Check whether two logical expressions, or two files line by line, are equivalent; exits with 1 if they differ and 2 on errors
+    Equiv {
+        /// Two files whose expressions are compared line by line
+        #[arg(short, long, value_name = "FILE", group = "inputs", required = true)]
+        file: Vec<PathBuf>,
+
+        /// Two logical expressions to compare
+        #[arg(
+            short,
+            long,
+            value_name = "EXPRESSION",
+            group = "inputs",
+            required = true
+        )]
+        expression: Vec<String>,
+    },
+
+    /// Check whether the premises of each argument entail its conclusion; exits with 1 if one is invalid and 2 on errors
+    Entails {
+        /// Path to a file with arguments, each a `premises:` line followed by a `conclude:` line
+        #[arg(short, long, value_name = "FILE", group = "argument", required = true)]
         file: Option<PathBuf>,
 
-        /// Logical expression for parsing
+        /// A premise of a single argument
+        #[arg(short, long, value_name = "EXPRESSION", requires = "conclusion")]
+        premise: Vec<String>,
+
+        /// The conclusion of a single argument
         #[arg(
             short,
             long,
             value_name = "EXPRESSION",
-            group = "input",
+            group = "argument",
             required = true
         )]
-        expression: Option<String>,
+        conclusion: Option<String>,
+    },
 
-        /// Boolean flag for showing or not AST
+    /// Report Post's classes of each logical expression and whether they form a functionally complete set of connectives
+    Complete {
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Show variable symmetries and the NPN canonical form of each logical expression with up to 6 variables
+    Npn {
+        #[command(flatten)]
+        input: Input,
+
+        /// Group the expressions into classes equal up to input permutation, input negation and output negation
         #[arg(short, long)]
-        ast: bool,
+        group: bool,
+    },
+
+    /// Count the satisfying assignments of each logical expression without enumerating its truth table
+    Count {
+        #[command(flatten)]
+        input: Input,
     },
+
+    /// Find a satisfying assignment of each logical expression with the SAT solver; exits with 1 if one is unsatisfiable and 2 on errors
+    Sat {
+        #[command(flatten)]
+        input: Input,
+
+        #[command(flatten)]
+        assumptions: Assumptions,
+
+        #[command(flatten)]
+        listing: Listing,
+    },
+
+    /// Check that each logical expression is unsatisfiable with the SAT solver; exits with 1 if one has a model and 2 on errors
+    Unsat {
+        #[command(flatten)]
+        input: Input,
+
+        #[command(flatten)]
+        assumptions: Assumptions,
+    },
+
+    /// Convert each logical expression into a circuit of a single universal gate
+    Convert {
+        #[command(flatten)]
+        input: Input,
+
+        /// Universal gate to use
+        #[arg(short, long, value_enum, default_value = "nand")]
+        gate: UniversalGate,
+    },
+}
+
+/// Reads the input content from a file or wraps a console expression into one line.
+fn read_input(input: &Input) -> Result<String> {
+    match (&input.file, &input.expression) {
+        (Some(f), None) => {
+            fs::read_to_string(f).with_context(|| format!("Failed to read file: {}", f.display()))
+        }
+
+        (None, Some(e)) => Ok(format!("{}\n", e)),
+
+        _ => unreachable!(),
+    }
+}
+
+/// Prints where the input content comes from.
+fn print_input_source(input: &Input) {
+    match &input.file {
+        Some(f) => println!("Processing file: {}\n", f.display()),
+        None => println!("Processing expression from console"),
+    }
+}
+
+/// An expression read from the input together with its don't-care conditions.
+struct Specification<'a> {
+    /// The source of the expression.
+    source: &'a str,
+    /// The parsed expression.
+    expression: Expression,
+    /// The sources of the `dontcare` lines following the expression.
+    dont_care_sources: Vec<&'a str>,
+    /// The OR of all don't-care conditions, if there are any.
+    dont_care: Option<Expression>,
+}
+
+impl Specification<'_> {
+    /// Builds the truth table of the expression, marking rows of the don't-care conditions and the given rows.
+    fn truth_table(&self, dont_cares: &[usize]) -> Result<TruthTable> {
+        let truth_table = match &self.dont_care {
+            Some(dont_care) => TruthTable::with_dont_care_condition(&self.expression, dont_care),
+            None => TruthTable::from(&self.expression),
+        };
+
+        Ok(truth_table.with_dont_cares(dont_cares)?)
+    }
+
+    /// Prints the source of the expression and its don't-care conditions.
+    fn print_source(&self) {
+        println!("Input: \"{}\"", self.source);
+        for source in &self.dont_care_sources {
+            println!("Don't care: \"{}\"", source);
+        }
+    }
+}
+
+/// Parses the input content and returns the pairs of all specifications in it.
+fn specification_pairs(content: &str) -> Result<Vec<Pair<'_, Rule>>> {
+    let mut pairs = parse(content)?;
+    let file_pair = pairs.next().context("Unexpected EOF")?;
+
+    Ok(file_pair
+        .into_inner()
+        .filter(|pair| pair.as_rule() == Rule::specification)
+        .collect())
+}
+
+/// Parses the input content and returns the pairs of all expressions in it, ignoring don't-care conditions.
+fn expression_pairs(content: &str) -> Result<Vec<Pair<'_, Rule>>> {
+    Ok(specification_pairs(content)?
+        .into_iter()
+        .map(|pair| pair.into_inner().next().unwrap())
+        .collect())
+}
+
+/// Parses the input content and returns all specifications in it.
+fn specifications(content: &str) -> Result<Vec<Specification<'_>>> {
+    Ok(specification_pairs(content)?
+        .into_iter()
+        .map(|pair| {
+            let mut inner = pair.into_inner();
+            let expression_pair = inner.next().unwrap();
+            let dont_care_pairs: Vec<Pair<Rule>> = inner.collect();
+
+            Specification {
+                source: expression_pair.as_str(),
+                expression: Expression::ast(expression_pair),
+                dont_care_sources: dont_care_pairs
+                    .iter()
+                    .map(|pair| pair.clone().into_inner().nth(1).unwrap().as_str())
+                    .collect(),
+                dont_care: dont_care_pairs
+                    .into_iter()
+                    .map(Expression::ast)
+                    .reduce(|left, right| Expression::Or(Box::new(left), Box::new(right))),
+            }
+        })
+        .collect())
+}
+
+/// Parses a variable written as a single uppercase letter.
+fn parse_variable(value: &str) -> Result<char, String> {
+    let mut chars = value.trim().chars();
+    match (chars.next(), chars.next()) {
+        (Some(iden), None) if iden.is_ascii_uppercase() => Ok(iden),
+        _ => Err(format!("\"{}\" is not a variable", value)),
+    }
+}
+
+/// Parses an assumption written as `A=1` or `A=0`.
+fn parse_assumption(value: &str) -> Result<(char, bool), String> {
+    let (variable, assumed) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected VARIABLE=VALUE, got \"{}\"", value))?;
+
+    let variable = parse_variable(variable)?;
+    let assumed = match assumed.trim() {
+        "1" => true,
+        "0" => false,
+        other => return Err(format!("\"{}\" is not 0 or 1", other)),
+    };
+
+    Ok((variable, assumed))
+}
+
+/// Solves each expression of the input under the assumptions, printing its model, or the listed models, if there is one.
+///
+/// # Returns
+/// Whether each expression is satisfiable.
+fn solve_expressions(
+    input: &Input,
+    assumptions: &Assumptions,
+    listing: Option<&Listing>,
+) -> Result<Vec<bool>> {
+    print_input_source(input);
+    let content = read_input(input)?;
+
+    if !assumptions.assume.is_empty() {
+        let assumed: Vec<String> = assumptions
+            .assume
+            .iter()
+            .map(|&(variable, value)| format!("{} = {}", variable, u8::from(value)))
+            .collect();
+        println!("Assuming: {}\n", assumed.join(", "));
+    }
+
+    let mut results = Vec::new();
+    for (index, pair) in expression_pairs(&content)?.into_iter().enumerate() {
+        println!("Expression {}", index + 1);
+        println!("Input: \"{}\"", pair.as_str());
+
+        let expression = Expression::ast(pair);
+        let limit = listing.and_then(|listing| listing.models);
+        let models: Vec<Model> = match (listing, limit) {
+            (Some(listing), Some(limit)) => {
+                let models = if listing.project.is_empty() {
+                    expression.models()
+                } else {
+                    expression.projected_models(&listing.project)
+                };
+                models
+                    .with_assumptions(&assumptions.assume)
+                    .take(limit.get())
+                    .collect()
+            }
+            _ => expression
+                .solve_with_assumptions(&assumptions.assume)
+                .into_iter()
+                .collect(),
+        };
+
+        if models.is_empty() {
+            println!("\nUnsatisfiable\n");
+            results.push(false);
+            continue;
+        }
+
+        println!("\nSatisfiable");
+        match limit {
+            Some(_) => {
+                println!("Models:");
+                for (number, model) in models.iter().enumerate() {
+                    println!("{:>3}. {}", number + 1, model);
+                }
+                println!();
+            }
+            None => println!("Model: {}\n", models[0]),
+        }
+        results.push(true);
+    }
+
+    Ok(results)
+}
+
+/// Formats a list of Post's classes separated by commas.
+fn format_classes(classes: &[PostClass]) -> String {
+    if classes.is_empty() {
+        return "none".to_string();
+    }
+
+    classes
+        .iter()
+        .map(PostClass::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
-fn main() -> Result<()> {
+/// Prints cubes as patterns followed by their product terms.
+fn print_cubes(title: &str, cubes: &[Cube], variables: &[char]) {
+    println!("{}:", title);
+    for cube in cubes {
+        println!(
+            "  {}  {}",
+            cube.to_pattern(variables.len()),
+            cube.to_term(variables)
+        );
+    }
+}
+
+/// Runs the command line, exiting with `0` on success, `1` if a check fails and `2` on errors, like `cmp` and `diff`.
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("Error: {:?}", error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Runs the command given on the command line.
+///
+/// # Returns
+/// [ExitCode::SUCCESS], or [CHECK_FAILED] if the command checks a property that does not hold.
+fn run() -> Result<ExitCode> {
     let cli = Cli::parse();
 
     if cli.author {
         println!("{}", env!("CARGO_PKG_AUTHORS"));
-        return Ok(());
+        return Ok(ExitCode::SUCCESS);
     }
 
     if let Some(command) = &cli.command {
@@ -74,48 +586,603 @@ fn main() -> Result<()> {
             }
 
             Commands::Parse {
-                file,
-                expression,
+                input,
+                dont_cares,
                 ast: show_ast,
+                support: show_support,
+                drop_vacuous,
+                nnf: show_nnf,
+                dnf: show_dnf,
+                anf: show_anf,
+                canonical: show_canonical,
+                classify_only,
             } => {
-                let content: String = match (file, expression) {
+                print_input_source(input);
+                let content = read_input(input)?;
+
+                for (index, specification) in specifications(&content)?.iter().enumerate() {
+                    println!("Expression {}", index + 1);
+                    specification.print_source();
+
+                    let expression = &specification.expression;
+
+                    if *show_ast {
+                        println!("\nAST: {:?}", expression);
+                    }
+
+                    if *show_nnf {
+                        println!("\nNNF: {}", expression.to_nnf());
+                    }
+
+                    if *show_dnf {
+                        println!("\nDNF: {}", expression.to_dnf());
+                    }
+
+                    let mut truth_table = specification
+                        .truth_table(&dont_cares.dont_care)
+                        .with_context(|| format!("Expression {}", index + 1))?;
+
+                    if *show_support {
+                        println!("\nSupport: {}", format_variables(&truth_table.support()));
+                        println!(
+                            "Vacuous: {}",
+                            format_variables(&truth_table.vacuous_variables())
+                        );
+                    }
+
+                    let classification = format!(
+                        "Classification: {} ({} of {} assignments satisfy)",
+                        truth_table.classify(),
+                        truth_table.satisfying_count(),
+                        truth_table.care_count()
+                    );
+
+                    if *drop_vacuous {
+                        truth_table = truth_table.without_vacuous_variables();
+                    }
+
+                    if *show_anf {
+                        let anf = truth_table.to_anf();
+                        println!("\nANF: {}", anf);
+                        println!("Degree: {}", anf.degree());
+                    }
+
+                    if *show_canonical {
+                        println!("\nMinterms: {}", truth_table.minterm_notation());
+                        println!("Maxterms: {}", truth_table.maxterm_notation());
+                    }
+
+                    if *classify_only {
+                        println!("\n{}\n", classification);
+                    } else {
+                        println!("\n{}", truth_table);
+                        println!("{}\n", classification);
+                    }
+                }
+            }
+
+            Commands::Stats { input, format } => {
+                let content = read_input(input)?;
+                let mut reports = Vec::new();
+
+                for (index, pair) in expression_pairs(&content)?.into_iter().enumerate() {
+                    let source = pair.as_str();
+                    let metrics = Metrics::from_pair(pair);
+
+                    match format {
+                        Format::Text => {
+                            println!("Expression {}", index + 1);
+                            println!("Input: \"{}\"", source);
+                            println!("{}\n", metrics);
+                        }
+                        Format::Json => reports.push(json!({
+                            "expression": index + 1,
+                            "input": source,
+                            "metrics": metrics,
+                        })),
+                    }
+                }
+
+                if let Format::Json = format {
+                    println!("{}", serde_json::to_string_pretty(&reports)?);
+                }
+            }
+
+            Commands::Cnf {
+                input,
+                tseitin,
+                dimacs,
+            } => {
+                print_input_source(input);
+                let content = read_input(input)?;
+
+                for (index, pair) in expression_pairs(&content)?.into_iter().enumerate() {
+                    println!("Expression {}", index + 1);
+                    println!("Input: \"{}\"", pair.as_str());
+
+                    let expression = Expression::ast(pair);
+                    let cnf = if *tseitin {
+                        expression.to_tseitin_cnf()
+                    } else {
+                        expression.to_cnf()
+                    };
+
+                    if *dimacs {
+                        println!("\n{}", cnf.to_dimacs());
+                    } else {
+                        println!("\nCNF: {}\n", cnf);
+                    }
+                }
+            }
+
+            Commands::Minimize {
+                input,
+                dont_cares,
+                method: _,
+                multi_output: true,
+            } => {
+                print_input_source(input);
+                let content = read_input(input)?;
+
+                let specifications = specifications(&content)?;
+                let mut tables = Vec::with_capacity(specifications.len());
+                for (index, specification) in specifications.iter().enumerate() {
+                    println!("Output {}: \"{}\"", index + 1, specification.source);
+                    for source in &specification.dont_care_sources {
+                        println!("  Don't care: \"{}\"", source);
+                    }
+                    tables.push(
+                        specification
+                            .truth_table(&dont_cares.dont_care)
+                            .with_context(|| format!("Expression {}", index + 1))?,
+                    );
+                }
+
+                let minimization = multi_output::minimize(&tables);
+                let variables = &minimization.variables;
+
+                let groups: Vec<String> = multi_output::support_groups(&tables)
+                    .iter()
+                    .map(|group| {
+                        let outputs: Vec<String> = group
+                            .iter()
+                            .map(|output| (output + 1).to_string())
+                            .collect();
+                        format!("{{{}}}", outputs.join(", "))
+                    })
+                    .collect();
+
+                println!("\nVariables: {}", format_variables(variables));
+                println!("Output groups: {}", groups.join(", "));
+                println!("Product terms:");
+                for (index, term) in minimization.terms.iter().enumerate() {
+                    let users: Vec<String> = minimization
+                        .users(index)
+                        .iter()
+                        .map(|output| (output + 1).to_string())
+                        .collect();
+                    println!(
+                        "  p{}  {}  {}  (outputs {})",
+                        index + 1,
+                        term.to_pattern(variables.len()),
+                        term.to_term(variables),
+                        users.join(", ")
+                    );
+                }
+
+                println!();
+                for output in 0..minimization.outputs.len() {
+                    println!("Output {}: {}", output + 1, minimization.to_dnf(output));
+                }
+
+                println!(
+                    "\nShared product terms: {}",
+                    minimization.shared_terms().len()
+                );
+                println!("PLA cost: {}", minimization.cost());
+                println!("Separate minimization: {}\n", PlaCost::separate(&tables));
+            }
+
+            Commands::Minimize {
+                input,
+                dont_cares,
+                method,
+                multi_output: false,
+            } => {
+                print_input_source(input);
+                let content = read_input(input)?;
+
+                for (index, specification) in specifications(&content)?.iter().enumerate() {
+                    println!("Expression {}", index + 1);
+                    specification.print_source();
+
+                    match method {
+                        Method::Exact => {
+                            let minimization = specification
+                                .truth_table(&dont_cares.dont_care)
+                                .with_context(|| format!("Expression {}", index + 1))?
+                                .minimize();
+                            let variables = &minimization.variables;
+
+                            println!("\nVariables: {}", format_variables(variables));
+                            print_cubes(
+                                "Prime implicants",
+                                &minimization.prime_implicants,
+                                variables,
+                            );
+                            print_cubes(
+                                "Essential prime implicants",
+                                &minimization.essential_prime_implicants,
+                                variables,
+                            );
+                            println!("Minimal SOP: {}\n", minimization.to_dnf());
+                        }
+
+                        Method::Espresso => {
+                            let cover = if !dont_cares.dont_care.is_empty() {
+                                specification
+                                    .truth_table(&dont_cares.dont_care)
+                                    .with_context(|| format!("Expression {}", index + 1))?
+                                    .minimize_heuristic()
+                            } else if let Some(dont_care) = &specification.dont_care {
+                                specification
+                                    .expression
+                                    .minimize_heuristic_with_dont_cares(dont_care)
+                            } else {
+                                specification.expression.minimize_heuristic()
+                            };
+
+                            println!("\nVariables: {}", format_variables(&cover.variables));
+                            print_cubes("Cover", &cover.cubes, &cover.variables);
+                            println!("Heuristic SOP: {}\n", cover.to_dnf());
+                        }
+                    }
+                }
+            }
+
+            Commands::Kmap {
+                input,
+                dont_cares,
+                format,
+                output,
+            } => {
+                let content = read_input(input)?;
+
+                let specifications = specifications(&content)?;
+                if matches!((format, output), (MapFormat::Svg, None)) && specifications.len() > 1 {
+                    bail!(
+                        "SVG maps of {} expressions need --output to write one file per expression",
+                        specifications.len()
+                    );
+                }
+
+                for (index, specification) in specifications.iter().enumerate() {
+                    if !matches!(format, MapFormat::Svg) {
+                        println!("Expression {}", index + 1);
+                        specification.print_source();
+                        println!();
+                    }
+
+                    let truth_table = specification
+                        .truth_table(&dont_cares.dont_care)
+                        .with_context(|| format!("Expression {}", index + 1))?;
+                    let map = match KarnaughMap::new(&truth_table) {
+                        Ok(map) => map,
+                        Err(error) => {
+                            match (format, output) {
+                                (MapFormat::Svg, None) => {
+                                    eprintln!("Expression {}: {}", index + 1, error)
+                                }
+                                (MapFormat::Svg, Some(_)) => {
+                                    println!("Expression {}: {}", index + 1, error)
+                                }
+                                (MapFormat::Text | MapFormat::Color, _) => println!("{}\n", error),
+                            }
+                            continue;
+                        }
+                    };
+
+                    match (format, output) {
+                        (MapFormat::Text, _) => println!("{}", map),
+                        (MapFormat::Color, _) => println!("{}", map.to_ansi()),
+                        (MapFormat::Svg, Some(directory)) => {
+                            let path = directory.join(format!("expression_{}.svg", index + 1));
+                            fs::write(&path, map.to_svg()).with_context(|| {
+                                format!("Failed to write file: {}", path.display())
+                            })?;
+                            println!("Expression {}: {}", index + 1, path.display());
+                        }
+                        (MapFormat::Svg, None) => print!("{}", map.to_svg()),
+                    }
+                }
+            }
+
+            Commands::Simplify { input, explain } => {
+                print_input_source(input);
+                let content = read_input(input)?;
+
+                for (index, pair) in expression_pairs(&content)?.into_iter().enumerate() {
+                    println!("Expression {}", index + 1);
+                    println!("Input: \"{}\"", pair.as_str());
+
+                    let simplification = Expression::ast(pair).simplify_with_steps();
+
+                    if *explain {
+                        println!();
+                        for (number, step) in simplification.steps.iter().enumerate() {
+                            println!("{:>3}. {}", number + 1, step);
+                            println!("     = {}", step.result);
+                        }
+                    }
+                    println!("\nSimplified: {}\n", simplification.result);
+                }
+            }
+
+            Commands::Equiv { file, expression } => {
+                let contents: Vec<String> = if file.is_empty() {
+                    expression.iter().map(|e| format!("{}\n", e)).collect()
+                } else {
+                    file.iter()
+                        .map(|f| {
+                            fs::read_to_string(f)
+                                .with_context(|| format!("Failed to read file: {}", f.display()))
+                        })
+                        .collect::<Result<_>>()?
+                };
+                let [left, right] = contents.as_slice() else {
+                    bail!("Expected exactly two inputs, got {}", contents.len());
+                };
+
+                let left = specifications(left)?;
+                let right = specifications(right)?;
+                if left.len() != right.len() {
+                    bail!(
+                        "Inputs have different numbers of expressions: {} and {}",
+                        left.len(),
+                        right.len()
+                    );
+                }
+
+                let mut all_equivalent = true;
+                for (index, (left, right)) in left.iter().zip(&right).enumerate() {
+                    println!("Pair {}", index + 1);
+                    println!("Left: \"{}\"", left.source);
+                    println!("Right: \"{}\"", right.source);
+
+                    match left.expression.counterexample(&right.expression) {
+                        None => println!("\nEquivalent\n"),
+                        Some(counterexample) => {
+                            all_equivalent = false;
+                            println!("\nNot equivalent");
+                            println!("Counterexample: {}", counterexample);
+                            println!(
+                                "Left output: {}, right output: {}\n",
+                                u8::from(counterexample.left),
+                                u8::from(counterexample.right)
+                            );
+                        }
+                    }
+                }
+
+                if !all_equivalent {
+                    return Ok(CHECK_FAILED);
+                }
+            }
+
+            Commands::Entails {
+                file,
+                premise,
+                conclusion,
+            } => {
+                let content = match (file, conclusion) {
                     (Some(f), None) => {
                         println!("Processing file: {}\n", f.display());
                         fs::read_to_string(f)
                             .with_context(|| format!("Failed to read file: {}", f.display()))?
                     }
-
-                    (None, Some(e)) => {
-                        println!("Processing expression from console");
-                        format!("{}\n", e)
+                    (None, Some(conclusion)) => {
+                        println!("Processing argument from console\n");
+                        format!(
+                            "premises: {}\nconclude: {}\n",
+                            premise.join(", "),
+                            conclusion
+                        )
                     }
-
                     _ => unreachable!(),
                 };
 
-                let mut pairs = parse(&content)?;
-                let file_pair = pairs.next().context("Unexpected EOF")?;
+                let file_pair = parse_arguments(&content)?
+                    .next()
+                    .context("Unexpected EOF")?;
+                let mut all_valid = true;
+                for (index, pair) in file_pair
+                    .into_inner()
+                    .filter(|pair| pair.as_rule() == Rule::argument)
+                    .enumerate()
+                {
+                    println!("Argument {}", index + 1);
+                    let (premises, conclusion) = Argument::sources(&pair);
+                    for (number, premise) in premises.iter().enumerate() {
+                        println!("Premise {}: \"{}\"", number + 1, premise);
+                    }
+                    println!("Conclusion: \"{}\"", conclusion);
+
+                    match Argument::ast(pair).counterexample() {
+                        None => println!("\nValid\n"),
+                        Some(counterexample) => {
+                            all_valid = false;
+                            println!("\nInvalid");
+                            println!("Counterexample: {}", counterexample);
+                            println!("All premises are 1 and the conclusion is 0\n");
+                        }
+                    }
+                }
+
+                if !all_valid {
+                    return Ok(CHECK_FAILED);
+                }
+            }
+
+            Commands::Complete { input } => {
+                print_input_source(input);
+                let content = read_input(input)?;
+
+                let mut tables = Vec::new();
+                for (index, pair) in expression_pairs(&content)?.into_iter().enumerate() {
+                    println!("Connective {}", index + 1);
+                    println!("Input: \"{}\"", pair.as_str());
+
+                    let table = TruthTable::from(&Expression::ast(pair));
+                    println!("Classes: {}\n", format_classes(&table.post_classes()));
+                    tables.push(table);
+                }
+
+                let common = post::common_post_classes(&tables);
+                if common.is_empty() {
+                    println!("Functionally complete");
+                } else {
+                    println!(
+                        "Not functionally complete: every connective is {}",
+                        format_classes(&common)
+                    );
+                }
+            }
 
-                let mut expression_count = 0;
+            Commands::Npn { input, group } => {
+                print_input_source(input);
+                let content = read_input(input)?;
 
-                for pair in file_pair.into_inner() {
-                    if pair.as_rule() == Rule::expression {
-                        expression_count += 1;
-                        println!("Expression {}", expression_count);
-                        println!("Input: \"{}\"", pair.as_str());
+                let mut classes: Vec<(NpnCanonical, Vec<(usize, &str)>)> = Vec::new();
+                let mut ungrouped: Vec<(usize, &str, NpnError)> = Vec::new();
+                for (index, pair) in expression_pairs(&content)?.into_iter().enumerate() {
+                    let source = pair.as_str();
+                    let table = TruthTable::from(&Expression::ast(pair));
+                    let canonical = table.npn_canonical();
 
-                        let expression = Expression::ast(pair);
+                    if *group {
+                        match canonical {
+                            Ok(canonical) => match classes.iter_mut().find(|(class, _)| {
+                                class.variables.len() == canonical.variables.len()
+                                    && class.table == canonical.table
+                            }) {
+                                Some((_, members)) => members.push((index, source)),
+                                None => classes.push((canonical, vec![(index, source)])),
+                            },
+                            Err(error) => ungrouped.push((index, source, error)),
+                        }
+                        continue;
+                    }
+
+                    println!("Expression {}", index + 1);
+                    println!("Input: \"{}\"", source);
 
-                        if *show_ast {
-                            println!("\nAST: {:?}", expression);
+                    let symmetries: Vec<String> =
+                        table.symmetries().iter().map(ToString::to_string).collect();
+                    let groups: Vec<String> = table
+                        .symmetric_groups()
+                        .iter()
+                        .map(|group| format!("{{{}}}", format_variables(group)))
+                        .collect();
+                    println!(
+                        "\nSymmetric pairs: {}",
+                        if symmetries.is_empty() {
+                            "none".to_string()
+                        } else {
+                            symmetries.join(", ")
+                        }
+                    );
+                    println!(
+                        "Symmetric groups: {}",
+                        if groups.is_empty() {
+                            "none".to_string()
+                        } else {
+                            groups.join(", ")
+                        }
+                    );
+                    match canonical {
+                        Ok(canonical) => {
+                            println!("NPN canonical table: 0x{}", canonical.to_hex());
+                            println!("Representative: {}\n", canonical.representative());
                         }
+                        Err(error) => println!("NPN canonical table: {}\n", error),
+                    }
+                }
 
-                        println!("\n{}", TruthTable::from(&expression));
+                for (number, (canonical, members)) in classes.iter().enumerate() {
+                    println!(
+                        "Class {}: 0x{} ({} variables)",
+                        number + 1,
+                        canonical.to_hex(),
+                        canonical.variables.len()
+                    );
+                    println!("Representative: {}", canonical.representative());
+                    for (index, source) in members {
+                        println!("  Expression {}: \"{}\"", index + 1, source);
                     }
+                    println!();
+                }
+
+                for (index, source, error) in &ungrouped {
+                    println!("Not grouped: Expression {}: \"{}\"", index + 1, source);
+                    println!("  {}\n", error);
+                }
+            }
+
+            Commands::Count { input } => {
+                print_input_source(input);
+                let content = read_input(input)?;
+
+                for (index, pair) in expression_pairs(&content)?.into_iter().enumerate() {
+                    println!("Expression {}", index + 1);
+                    println!("Input: \"{}\"", pair.as_str());
+
+                    let expression = Expression::ast(pair);
+                    let total = BigUint::from(1u8) << expression.variables().len();
+                    println!(
+                        "\nModels: {} of {} assignments\n",
+                        expression.count_models(),
+                        total
+                    );
+                }
+            }
+
+            Commands::Sat {
+                input,
+                assumptions,
+                listing,
+            } => {
+                if solve_expressions(input, assumptions, Some(listing))?.contains(&false) {
+                    return Ok(CHECK_FAILED);
+                }
+            }
+
+            Commands::Unsat { input, assumptions } => {
+                if solve_expressions(input, assumptions, None)?.contains(&true) {
+                    return Ok(CHECK_FAILED);
+                }
+            }
+
+            Commands::Convert { input, gate } => {
+                print_input_source(input);
+                let content = read_input(input)?;
+
+                for (index, pair) in expression_pairs(&content)?.into_iter().enumerate() {
+                    println!("Expression {}", index + 1);
+                    println!("Input: \"{}\"", pair.as_str());
+
+                    let expression = Expression::ast(pair);
+                    let converted = expression.to_universal(*gate);
+
+                    println!("\n{}-only: {}", gate, converted);
+                    println!(
+                        "Gates: {} before, {} after\n",
+                        expression.gate_count(),
+                        converted.gate_count()
+                    );
                 }
             }
         }
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
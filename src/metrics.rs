@@ -0,0 +1,307 @@
+use crate::ast::Expression;
+use crate::parser::Rule;
+use pest::iterators::Pair;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// A logical operator that can appear in an [Expression].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum Operator {
+    /// A unary NOT operation.
+    Not,
+    /// A binary AND operation.
+    And,
+    /// A binary NAND operation.
+    Nand,
+    /// A binary OR operation.
+    Or,
+    /// A binary NOR operation.
+    Nor,
+    /// A binary XOR operation.
+    Xor,
+    /// A binary XNOR operation.
+    Xnor,
+    /// A binary implication.
+    Implies,
+}
+
+/// Estimated size of a two-level (sum-of-products) realization of an expression.
+///
+/// The estimate is obtained by expanding the expression into a DNF without any minimization, so it is an upper bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TwoLevelCost {
+    /// The number of product terms.
+    pub products: u64,
+    /// The total number of literals in all product terms.
+    pub literals: u64,
+}
+
+/// Structural metrics of an [Expression].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Metrics {
+    /// The total number of AST nodes.
+    pub node_count: usize,
+    /// The length of the longest path from the root to a leaf, counted in nodes.
+    pub depth: usize,
+    /// The number of identifier occurrences.
+    pub literal_count: usize,
+    /// The number of occurrences of each operator.
+    pub operators: BTreeMap<Operator, usize>,
+    /// The number of distinct variables.
+    pub variable_count: usize,
+    /// The number of redundant double negations.
+    ///
+    /// [Expression::ast] drops pairs of consecutive NOT operators, so only [Metrics::from_pair] counts the ones
+    /// written in the source, while [Expression::metrics] counts those left in the AST.
+    pub double_negations: usize,
+    /// The estimated two-level cost.
+    pub two_level_cost: TwoLevelCost,
+}
+
+impl Display for Operator {
+    /// Formats an [Operator] using its keyword.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            Operator::Not => "NOT",
+            Operator::And => "AND",
+            Operator::Nand => "NAND",
+            Operator::Or => "OR",
+            Operator::Nor => "NOR",
+            Operator::Xor => "XOR",
+            Operator::Xnor => "XNOR",
+            Operator::Implies => "IMPLIES",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+impl TwoLevelCost {
+    /// Returns the gate-input cost, i.e. the number of literals plus the number of product terms.
+    pub fn cost(&self) -> u64 {
+        self.literals.saturating_add(self.products)
+    }
+
+    /// Cost of the conjunction of two sums of products.
+    fn product(self, other: Self) -> Self {
+        Self {
+            products: self.products.saturating_mul(other.products),
+            literals: self
+                .literals
+                .saturating_mul(other.products)
+                .saturating_add(other.literals.saturating_mul(self.products)),
+        }
+    }
+
+    /// Cost of the disjunction of two sums of products.
+    fn sum(self, other: Self) -> Self {
+        Self {
+            products: self.products.saturating_add(other.products),
+            literals: self.literals.saturating_add(other.literals),
+        }
+    }
+}
+
+impl Metrics {
+    /// Computes structural metrics of a parsed expression, including the double negations written in its source.
+    ///
+    /// # Arguments
+    /// * `pair` - A `Pair<Rule>` from [pest] crate representing a parsed expression.
+    ///
+    /// # Returns
+    /// The [Metrics] of the expression built from `pair`.
+    pub fn from_pair(pair: Pair<Rule>) -> Metrics {
+        let double_negations = source_double_negations(&pair);
+
+        let mut metrics = Expression::ast(pair).metrics();
+        metrics.double_negations += double_negations;
+
+        metrics
+    }
+}
+
+impl Display for Metrics {
+    /// Formats [Metrics] for printing.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Nodes: {}", self.node_count)?;
+        writeln!(f, "Depth: {}", self.depth)?;
+        writeln!(f, "Literals: {}", self.literal_count)?;
+        writeln!(f, "Variables: {}", self.variable_count)?;
+        writeln!(f, "Double negations: {}", self.double_negations)?;
+
+        write!(f, "Operators:")?;
+        if self.operators.is_empty() {
+            write!(f, " none")?;
+        }
+        for (operator, count) in &self.operators {
+            write!(f, " {}={}", operator, count)?;
+        }
+        writeln!(f)?;
+
+        write!(
+            f,
+            "Two-level cost: {} ({} products, {} literals)",
+            self.two_level_cost.cost(),
+            self.two_level_cost.products,
+            self.two_level_cost.literals
+        )
+    }
+}
+
+impl Expression {
+    /// Computes structural metrics of the expression.
+    ///
+    /// Double negations written in the source are already dropped from the AST; use [Metrics::from_pair] to count them.
+    ///
+    /// # Returns
+    /// The [Metrics] of the expression.
+    pub fn metrics(&self) -> Metrics {
+        let mut metrics = Metrics {
+            node_count: 0,
+            depth: 0,
+            literal_count: 0,
+            operators: BTreeMap::new(),
+            variable_count: self.variables().len(),
+            double_negations: 0,
+            two_level_cost: self.two_level_cost().0,
+        };
+        metrics.depth = self.collect_metrics(&mut metrics);
+
+        metrics
+    }
+
+    /// Helper method for recursively counting nodes, literals and operators.
+    ///
+    /// # Returns
+    /// The depth of the visited subtree.
+    fn collect_metrics(&self, metrics: &mut Metrics) -> usize {
+        metrics.node_count += 1;
+
+        let (operator, children): (Option<Operator>, Vec<&Expression>) = match self {
+            Expression::Constant(_) => (None, vec![]),
+            Expression::Identifier(_) => {
+                metrics.literal_count += 1;
+                (None, vec![])
+            }
+            Expression::Not(expr) => {
+                if matches!(**expr, Expression::Not(_)) {
+                    metrics.double_negations += 1;
+                }
+                (Some(Operator::Not), vec![expr])
+            }
+            Expression::And(left, right) => (Some(Operator::And), vec![left, right]),
+            Expression::Nand(left, right) => (Some(Operator::Nand), vec![left, right]),
+            Expression::Or(left, right) => (Some(Operator::Or), vec![left, right]),
+            Expression::Nor(left, right) => (Some(Operator::Nor), vec![left, right]),
+            Expression::Xor(left, right) => (Some(Operator::Xor), vec![left, right]),
+            Expression::Xnor(left, right) => (Some(Operator::Xnor), vec![left, right]),
+            Expression::Implies(left, right) => (Some(Operator::Implies), vec![left, right]),
+        };
+
+        if let Some(operator) = operator {
+            *metrics.operators.entry(operator).or_insert(0) += 1;
+        }
+
+        1 + children
+            .into_iter()
+            .map(|child| child.collect_metrics(metrics))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Helper method for estimating the two-level cost of the expression and of its complement.
+    ///
+    /// # Returns
+    /// A tuple of the estimated [TwoLevelCost] of the expression and of its negation.
+    fn two_level_cost(&self) -> (TwoLevelCost, TwoLevelCost) {
+        let empty = TwoLevelCost {
+            products: 0,
+            literals: 0,
+        };
+        let tautology = TwoLevelCost {
+            products: 1,
+            literals: 0,
+        };
+        let literal = TwoLevelCost {
+            products: 1,
+            literals: 1,
+        };
+
+        match self {
+            Expression::Constant(true) => (tautology, empty),
+            Expression::Constant(false) => (empty, tautology),
+            Expression::Identifier(_) => (literal, literal),
+            Expression::Not(expr) => {
+                let (positive, negative) = expr.two_level_cost();
+                (negative, positive)
+            }
+            Expression::And(left, right) | Expression::Nand(left, right) => {
+                let (left_positive, left_negative) = left.two_level_cost();
+                let (right_positive, right_negative) = right.two_level_cost();
+                let positive = left_positive.product(right_positive);
+                let negative = left_negative.sum(right_negative);
+
+                if matches!(self, Expression::And(..)) {
+                    (positive, negative)
+                } else {
+                    (negative, positive)
+                }
+            }
+            Expression::Or(left, right) | Expression::Nor(left, right) => {
+                let (left_positive, left_negative) = left.two_level_cost();
+                let (right_positive, right_negative) = right.two_level_cost();
+                let positive = left_positive.sum(right_positive);
+                let negative = left_negative.product(right_negative);
+
+                if matches!(self, Expression::Or(..)) {
+                    (positive, negative)
+                } else {
+                    (negative, positive)
+                }
+            }
+            Expression::Xor(left, right) | Expression::Xnor(left, right) => {
+                let (left_positive, left_negative) = left.two_level_cost();
+                let (right_positive, right_negative) = right.two_level_cost();
+                let positive = left_positive
+                    .product(right_negative)
+                    .sum(left_negative.product(right_positive));
+                let negative = left_positive
+                    .product(right_positive)
+                    .sum(left_negative.product(right_negative));
+
+                if matches!(self, Expression::Xor(..)) {
+                    (positive, negative)
+                } else {
+                    (negative, positive)
+                }
+            }
+            Expression::Implies(left, right) => {
+                let (left_positive, left_negative) = left.two_level_cost();
+                let (right_positive, right_negative) = right.two_level_cost();
+
+                (
+                    left_negative.sum(right_positive),
+                    left_positive.product(right_negative),
+                )
+            }
+        }
+    }
+}
+
+/// Helper function for counting the `NOT NOT` pairs written in the source of a parsed expression.
+///
+/// # Returns
+/// The number of removable `NOT NOT` pairs.
+fn source_double_negations(pair: &Pair<Rule>) -> usize {
+    pair.clone()
+        .into_inner()
+        .flatten()
+        .filter(|inner| inner.as_rule() == Rule::term)
+        .map(|term| {
+            term.into_inner()
+                .take_while(|inner| inner.as_rule() == Rule::not_operator)
+                .count()
+                / 2
+        })
+        .sum()
+}
@@ -0,0 +1,423 @@
+use crate::cube::Cube;
+use crate::dnf::Dnf;
+use crate::quine_mccluskey::prime_implicants;
+use crate::truth_table::TruthTable;
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+/// The cost of a programmable logic array implementing a sum of products for every output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct PlaCost {
+    /// The number of product terms, i.e. rows of the AND plane.
+    pub products: usize,
+    /// The number of literals feeding the AND plane.
+    pub literals: usize,
+    /// The number of connections in the OR plane, i.e. product terms summed by the outputs.
+    pub connections: usize,
+}
+
+impl PlaCost {
+    /// Computes the cost of minimizing every table separately, without sharing product terms between outputs.
+    pub fn separate(tables: &[TruthTable]) -> Self {
+        tables
+            .iter()
+            .map(|table| table.minimize().cover)
+            .fold(PlaCost::default(), |cost, cover| PlaCost {
+                products: cost.products + cover.len(),
+                literals: cost.literals + cover.iter().map(Cube::literal_count).sum::<usize>(),
+                connections: cost.connections + cover.len(),
+            })
+    }
+}
+
+impl Display for PlaCost {
+    /// Formats a [PlaCost] as `3 products, 5 literals, 4 connections`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} products, {} literals, {} connections",
+            self.products, self.literals, self.connections
+        )
+    }
+}
+
+/// The result of a multi-output two-level minimization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiOutputMinimization {
+    /// A sorted list of unique variables of all outputs; bit `i` of every cube refers to the variable with index `i`.
+    pub variables: Vec<char>,
+    /// The product terms of the AND plane, shared by all outputs.
+    pub terms: Vec<Cube>,
+    /// For every output, the indices of the terms it sums.
+    pub outputs: Vec<Vec<usize>>,
+}
+
+impl MultiOutputMinimization {
+    /// Converts the sum of products of an output into a [Dnf].
+    ///
+    /// # Arguments
+    /// * `output` - The index of the output.
+    pub fn to_dnf(&self, output: usize) -> Dnf {
+        Dnf {
+            terms: self.outputs[output]
+                .iter()
+                .map(|&term| self.terms[term].to_term(&self.variables))
+                .collect(),
+        }
+    }
+
+    /// Returns the indices of the outputs that sum a term.
+    pub fn users(&self, term: usize) -> Vec<usize> {
+        (0..self.outputs.len())
+            .filter(|&output| self.outputs[output].contains(&term))
+            .collect()
+    }
+
+    /// Returns the indices of the terms that are summed by more than one output.
+    pub fn shared_terms(&self) -> Vec<usize> {
+        (0..self.terms.len())
+            .filter(|&term| self.users(term).len() > 1)
+            .collect()
+    }
+
+    /// Returns the cost of the PLA implementing all outputs with shared terms.
+    pub fn cost(&self) -> PlaCost {
+        PlaCost {
+            products: self.terms.len(),
+            literals: self.terms.iter().map(Cube::literal_count).sum(),
+            connections: self.outputs.iter().map(Vec::len).sum(),
+        }
+    }
+}
+
+/// The ON-set and the allowed set (ON-set and don't-cares) of one output over the common variables.
+struct Output {
+    on: Vec<bool>,
+    allowed: Vec<bool>,
+}
+
+/// Splits outputs into groups connected by shared variables of their functional support.
+///
+/// Two outputs are in the same group if they depend on a common variable, directly or through other outputs of the
+/// group. Outputs of different groups cannot share product terms other than constants, so each group is minimized
+/// on its own.
+///
+/// # Returns
+/// The indices of the outputs of every group, each sorted, in the order of their first output.
+pub fn support_groups(tables: &[TruthTable]) -> Vec<Vec<usize>> {
+    let mut groups: Vec<(Vec<usize>, Vec<char>)> = Vec::new();
+
+    for (output, table) in tables.iter().enumerate() {
+        let support = table.support();
+        let (connected, mut rest): (Vec<_>, Vec<_>) =
+            groups.into_iter().partition(|(_, variables)| {
+                variables.iter().any(|variable| support.contains(variable))
+            });
+
+        let mut outputs = vec![output];
+        let mut variables = support;
+        for (other_outputs, other_variables) in connected {
+            outputs.extend(other_outputs);
+            variables.extend(other_variables);
+        }
+        outputs.sort();
+        variables.sort();
+        variables.dedup();
+
+        rest.push((outputs, variables));
+        groups = rest;
+    }
+
+    groups.sort();
+    groups.into_iter().map(|(outputs, _)| outputs).collect()
+}
+
+/// Minimizes several functions together, sharing product terms between them.
+///
+/// The outputs are split into [support_groups] and every group is minimized over the variables it depends on.
+/// Candidate terms of a group are the prime implicants of every output and of the product of every pair of outputs
+/// whose ON-sets overlap, each tagged with the outputs it can be used in, so their number grows quadratically with
+/// the number of outputs. Essential terms are selected first and the rest of the ON-sets are covered greedily by the
+/// terms covering the most uncovered minterms, then every output drops terms that became redundant for it.
+/// The cover is a heuristic and is not guaranteed to be minimal.
+///
+/// # Arguments
+/// * `tables` - One truth table per output; their don't-care rows are used and their variables may differ.
+///
+/// # Returns
+/// A [MultiOutputMinimization] over the union of the variables of all tables.
+pub fn minimize(tables: &[TruthTable]) -> MultiOutputMinimization {
+    let mut variables: Vec<char> = tables
+        .iter()
+        .flat_map(|table| table.variables.iter().copied())
+        .collect();
+    variables.sort();
+    variables.dedup();
+
+    let reduced: Vec<TruthTable> = tables
+        .iter()
+        .map(TruthTable::without_vacuous_variables)
+        .collect();
+
+    let mut assignments: Vec<Vec<Cube>> = vec![Vec::new(); tables.len()];
+    for group in support_groups(tables) {
+        let mut group_variables: Vec<char> = group
+            .iter()
+            .flat_map(|&output| reduced[output].variables.iter().copied())
+            .collect();
+        group_variables.sort();
+        group_variables.dedup();
+        let positions: Vec<usize> = group_variables
+            .iter()
+            .map(|variable| variables.binary_search(variable).unwrap())
+            .collect();
+
+        let outputs: Vec<Output> = group
+            .iter()
+            .map(|&output| expand_table(&reduced[output], &group_variables))
+            .collect();
+
+        for (&output, cubes) in group
+            .iter()
+            .zip(minimize_group(&outputs, group_variables.len()))
+        {
+            assignments[output] = cubes.iter().map(|cube| widen(cube, &positions)).collect();
+        }
+    }
+
+    let mut terms: Vec<Cube> = assignments.iter().flatten().copied().collect();
+    terms.sort();
+    terms.dedup();
+
+    let outputs = assignments
+        .iter()
+        .map(|cubes| {
+            let mut indices: Vec<usize> = cubes
+                .iter()
+                .map(|cube| terms.binary_search(cube).unwrap())
+                .collect();
+            indices.sort();
+            indices
+        })
+        .collect();
+
+    MultiOutputMinimization {
+        variables,
+        terms,
+        outputs,
+    }
+}
+
+/// Minimizes the outputs of one group over its variables.
+///
+/// # Returns
+/// For every output, the cubes it sums.
+fn minimize_group(outputs: &[Output], variables_length: usize) -> Vec<Vec<Cube>> {
+    let mut candidates = BTreeSet::new();
+    for first in 0..outputs.len() {
+        for second in first..outputs.len() {
+            collect_candidates(
+                &outputs[first],
+                &outputs[second],
+                variables_length,
+                &mut candidates,
+            );
+        }
+    }
+
+    let tagged: Vec<(Cube, Vec<usize>)> = candidates
+        .into_iter()
+        .map(|cube| {
+            let tag = (0..outputs.len())
+                .filter(|&output| usable(&cube, &outputs[output], variables_length))
+                .collect();
+            (cube, tag)
+        })
+        .collect();
+    let tagged: Vec<(Cube, Vec<usize>)> = tagged
+        .iter()
+        .filter(|(cube, tag)| {
+            !tagged.iter().any(|(other, other_tag)| {
+                other != cube
+                    && other.contains(cube)
+                    && tag.iter().all(|output| other_tag.contains(output))
+            })
+        })
+        .cloned()
+        .collect();
+
+    let selected = select_cover(&tagged, outputs);
+
+    (0..outputs.len())
+        .map(|output| {
+            irredundant(
+                &selected,
+                &tagged,
+                &outputs[output],
+                output,
+                variables_length,
+            )
+        })
+        .collect()
+}
+
+/// Moves the variables of a cube of a group to their positions among the variables of all outputs.
+fn widen(cube: &Cube, positions: &[usize]) -> Cube {
+    let spread = |bits: u64| -> u64 {
+        positions
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| bits >> index & 1 == 1)
+            .map(|(_, &position)| 1 << position)
+            .sum()
+    };
+
+    Cube::new(spread(cube.values), spread(cube.mask))
+}
+
+/// Converts a table into an [Output] over the common variables.
+fn expand_table(table: &TruthTable, variables: &[char]) -> Output {
+    let positions: Vec<usize> = table
+        .variables
+        .iter()
+        .map(|variable| variables.binary_search(variable).unwrap())
+        .collect();
+
+    let rows = (0..1usize << variables.len()).map(|row| {
+        let local: usize = positions
+            .iter()
+            .enumerate()
+            .filter(|&(_, &position)| row >> position & 1 == 1)
+            .map(|(index, _)| 1 << index)
+            .sum();
+        &table.rows[local]
+    });
+
+    let (on, allowed) = rows
+        .map(|row| (!row.dont_care && row.result, row.dont_care || row.result))
+        .unzip();
+
+    Output { on, allowed }
+}
+
+/// Collects the prime implicants of the product of two outputs, or of one output if both are the same.
+///
+/// Pairs whose ON-sets do not overlap are skipped, as a term shared by them would only cover don't-cares of one.
+fn collect_candidates(
+    first: &Output,
+    second: &Output,
+    variables_length: usize,
+    candidates: &mut BTreeSet<Cube>,
+) {
+    let rows = 0..first.on.len();
+    if !rows.clone().any(|row| first.on[row] && second.on[row]) {
+        return;
+    }
+
+    let (minterms, dont_cares): (Vec<usize>, Vec<usize>) = rows
+        .filter(|&row| first.allowed[row] && second.allowed[row])
+        .partition(|&row| first.on[row] || second.on[row]);
+
+    candidates.extend(prime_implicants(variables_length, &minterms, &dont_cares));
+}
+
+/// Checks whether a cube lies within the allowed set of an output and covers at least one of its minterms.
+fn usable(cube: &Cube, output: &Output, variables_length: usize) -> bool {
+    let mut minterms = cube.minterms(variables_length);
+    let mut covers = false;
+    minterms.all(|minterm| {
+        covers |= output.on[minterm];
+        output.allowed[minterm]
+    }) && covers
+}
+
+/// Selects tagged candidates covering every minterm of every output.
+///
+/// Candidates that are the only ones covering some minterm of an output are selected first, then the candidate covering the most
+/// uncovered minterms, preferring fewer literals, is selected until all are covered.
+fn select_cover(tagged: &[(Cube, Vec<usize>)], outputs: &[Output]) -> Vec<Cube> {
+    let mut uncovered: BTreeSet<(usize, usize)> = outputs
+        .iter()
+        .enumerate()
+        .flat_map(|(index, output)| {
+            (0..output.on.len())
+                .filter(|&row| output.on[row])
+                .map(move |row| (index, row))
+        })
+        .collect();
+    let covers = |candidate: usize, (output, minterm): (usize, usize)| {
+        let (cube, tag) = &tagged[candidate];
+        tag.contains(&output) && cube.contains_minterm(minterm)
+    };
+
+    let mut selected: Vec<usize> = Vec::new();
+    for &element in &uncovered {
+        let mut covering = (0..tagged.len()).filter(|&candidate| covers(candidate, element));
+        if let (Some(candidate), None) = (covering.next(), covering.next())
+            && !selected.contains(&candidate)
+        {
+            selected.push(candidate);
+        }
+    }
+    uncovered.retain(|&element| !selected.iter().any(|&candidate| covers(candidate, element)));
+
+    while !uncovered.is_empty() {
+        let best = (0..tagged.len())
+            .filter(|candidate| !selected.contains(candidate))
+            .max_by_key(|&candidate| {
+                let gain = uncovered
+                    .iter()
+                    .filter(|&&element| covers(candidate, element))
+                    .count();
+                (gain, std::cmp::Reverse(tagged[candidate].0.literal_count()))
+            })
+            .unwrap();
+        selected.push(best);
+        uncovered.retain(|&element| !covers(best, element));
+    }
+
+    selected
+        .into_iter()
+        .map(|candidate| tagged[candidate].0)
+        .collect()
+}
+
+/// Chooses the selected cubes an output sums, dropping cubes covered by the others, the most expensive first.
+fn irredundant(
+    selected: &[Cube],
+    tagged: &[(Cube, Vec<usize>)],
+    output: &Output,
+    index: usize,
+    variables_length: usize,
+) -> Vec<Cube> {
+    let mut cubes: Vec<Cube> = selected
+        .iter()
+        .filter(|cube| {
+            tagged
+                .iter()
+                .any(|(other, tag)| other == *cube && tag.contains(&index))
+        })
+        .copied()
+        .collect();
+    cubes.sort_by_key(|cube| std::cmp::Reverse(cube.literal_count()));
+
+    let mut position = 0;
+    while position < cubes.len() {
+        let redundant = cubes[position]
+            .minterms(variables_length)
+            .filter(|&minterm| output.on[minterm])
+            .all(|minterm| {
+                cubes
+                    .iter()
+                    .enumerate()
+                    .any(|(other, cube)| other != position && cube.contains_minterm(minterm))
+            });
+        if redundant {
+            cubes.remove(position);
+        } else {
+            position += 1;
+        }
+    }
+
+    cubes
+}
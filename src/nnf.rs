@@ -0,0 +1,75 @@
+use crate::ast::Expression;
+
+impl Expression {
+    /// Converts the expression into negation normal form (NNF).
+    ///
+    /// Negations are pushed down to identifiers using De Morgan's laws, and NAND, NOR, XOR, XNOR and implication are expanded,
+    /// so the result contains only AND, OR, negated identifiers, identifiers and constants.
+    ///
+    /// # Returns
+    /// A logically equivalent [Expression] in negation normal form.
+    pub fn to_nnf(&self) -> Expression {
+        self.nnf(false)
+    }
+
+    /// Helper method for recursively converting the expression into NNF.
+    ///
+    /// # Arguments
+    /// * `negated` - Whether the expression is under an odd number of negations.
+    fn nnf(&self, negated: bool) -> Expression {
+        match self {
+            Expression::Constant(value) => Expression::Constant(*value != negated),
+            Expression::Identifier(iden) => {
+                let identifier = Expression::Identifier(*iden);
+                if negated {
+                    Expression::Not(Box::new(identifier))
+                } else {
+                    identifier
+                }
+            }
+            Expression::Not(expr) => expr.nnf(!negated),
+            Expression::And(left, right) => junction(left, right, true, negated),
+            Expression::Nand(left, right) => junction(left, right, true, !negated),
+            Expression::Or(left, right) => junction(left, right, false, negated),
+            Expression::Nor(left, right) => junction(left, right, false, !negated),
+            Expression::Xor(left, right) => exclusive_or(left, right, negated),
+            Expression::Xnor(left, right) => exclusive_or(left, right, !negated),
+            Expression::Implies(left, right) => {
+                let antecedent = Box::new(left.nnf(!negated));
+                let consequent = Box::new(right.nnf(negated));
+
+                if negated {
+                    Expression::And(antecedent, consequent)
+                } else {
+                    Expression::Or(antecedent, consequent)
+                }
+            }
+        }
+    }
+}
+
+/// Converts `left AND right` or `left OR right` (or its negation) into NNF.
+///
+/// By De Morgan's laws, negating a conjunction or a disjunction negates both operands and swaps the operator.
+///
+/// # Arguments
+/// * `conjunctive` - `true` for AND, `false` for OR.
+/// * `negated` - Whether the operation is negated.
+fn junction(left: &Expression, right: &Expression, conjunctive: bool, negated: bool) -> Expression {
+    let left = Box::new(left.nnf(negated));
+    let right = Box::new(right.nnf(negated));
+
+    if conjunctive != negated {
+        Expression::And(left, right)
+    } else {
+        Expression::Or(left, right)
+    }
+}
+
+/// Converts `left XOR right` (or its negation) into NNF as a sum of two products.
+fn exclusive_or(left: &Expression, right: &Expression, negated: bool) -> Expression {
+    let first = Expression::And(Box::new(left.nnf(false)), Box::new(right.nnf(!negated)));
+    let second = Expression::And(Box::new(left.nnf(true)), Box::new(right.nnf(negated)));
+
+    Expression::Or(Box::new(first), Box::new(second))
+}
@@ -0,0 +1,274 @@
+use crate::ast::Expression;
+use crate::quine_mccluskey::minimize;
+use crate::truth_table::{TruthTable, TruthTableRow};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// The largest number of variables of a function whose NPN canonical form can be computed.
+///
+/// The canonical table is packed into a `u64`, and all `n! * 2^(n + 1)` transformations are tried.
+pub const NPN_MAX_VARIABLES: usize = 6;
+
+/// Custom NPN error created using the [thiserror] crate.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NpnError {
+    /// Error for functions with more variables than [NPN_MAX_VARIABLES].
+    #[error("NPN canonical form supports at most {maximum} variables, got {variables}")]
+    TooManyVariables { variables: usize, maximum: usize },
+}
+
+/// A symmetry of a function in a pair of variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symmetry {
+    /// The first variable of the pair.
+    pub first: char,
+    /// The second variable of the pair.
+    pub second: char,
+    /// `false` if swapping the variables keeps the function, `true` if swapping them and negating both keeps it.
+    pub negated: bool,
+}
+
+impl Display for Symmetry {
+    /// Formats a [Symmetry] as `(A, B)`, or `(A, NOT B)` for a negated one.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.negated {
+            write!(f, "({}, NOT {})", self.first, self.second)
+        } else {
+            write!(f, "({}, {})", self.first, self.second)
+        }
+    }
+}
+
+/// A transformation of inputs and output mapping a function onto its NPN canonical form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NpnTransform {
+    /// For every variable of the canonical form, the index of the original variable it is fed into.
+    pub permutation: Vec<usize>,
+    /// Bit `i` is set if variable `i` of the canonical form is negated before it is fed into the original function.
+    pub input_negations: u64,
+    /// Whether the output of the original function is negated.
+    pub output_negation: bool,
+}
+
+/// The NPN canonical form of a function: the smallest truth table reachable by permuting and negating its inputs and
+/// negating its output.
+///
+/// Two functions with the same number of variables are NPN-equivalent exactly when their canonical tables are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NpnCanonical {
+    /// A sorted list of the variables of the original function, reused for the canonical form.
+    pub variables: Vec<char>,
+    /// The canonical truth table, bit `r` being the result of row `r`.
+    pub table: u64,
+    /// The transformation taking the original function to the canonical form.
+    pub transform: NpnTransform,
+}
+
+impl NpnCanonical {
+    /// Converts the canonical table into a [TruthTable] over the original variables.
+    pub fn to_truth_table(&self) -> TruthTable {
+        let rows = (0..1usize << self.variables.len())
+            .map(|row| {
+                TruthTableRow::new(
+                    (0..self.variables.len())
+                        .map(|index| row >> index & 1 == 1)
+                        .collect(),
+                    self.table >> row & 1 == 1,
+                )
+            })
+            .collect();
+
+        TruthTable {
+            variables: self.variables.clone(),
+            rows,
+        }
+    }
+
+    /// Returns a minimal sum of products of the canonical table as the representative of the NPN class.
+    pub fn representative(&self) -> Expression {
+        let minterms: Vec<usize> = (0..1usize << self.variables.len())
+            .filter(|&row| self.table >> row & 1 == 1)
+            .collect();
+
+        minimize(&self.variables, &minterms, &[]).to_expression()
+    }
+
+    /// Formats the canonical table as hexadecimal digits, one digit per four rows, the last row first.
+    pub fn to_hex(&self) -> String {
+        let digits = ((1usize << self.variables.len()) / 4).max(1);
+        format!("{:0digits$x}", self.table, digits = digits)
+    }
+}
+
+impl TruthTable {
+    /// Checks whether the function keeps its results when two variables are swapped.
+    ///
+    /// # Arguments
+    /// * `first`, `second` - Indices of the variables.
+    /// * `negated` - Whether both variables are also negated by the swap.
+    pub fn is_symmetric_in(&self, first: usize, second: usize, negated: bool) -> bool {
+        (0..self.rows.len()).all(|row| {
+            let first_value = row >> first & 1;
+            let second_value = row >> second & 1;
+            let (new_first, new_second) = if negated {
+                (second_value ^ 1, first_value ^ 1)
+            } else {
+                (second_value, first_value)
+            };
+            let swapped =
+                row & !(1 << first | 1 << second) | new_first << first | new_second << second;
+
+            self.rows[row].result == self.rows[swapped].result
+        })
+    }
+
+    /// Collects all pairs of variables the function is symmetric or negated symmetric in.
+    pub fn symmetries(&self) -> Vec<Symmetry> {
+        let length = self.variables.len();
+        let mut symmetries = Vec::new();
+
+        for first in 0..length {
+            for second in first + 1..length {
+                for negated in [false, true] {
+                    if self.is_symmetric_in(first, second, negated) {
+                        symmetries.push(Symmetry {
+                            first: self.variables[first],
+                            second: self.variables[second],
+                            negated,
+                        });
+                    }
+                }
+            }
+        }
+
+        symmetries
+    }
+
+    /// Groups variables that can be swapped freely without changing the function.
+    ///
+    /// Plain symmetry is transitive, so variables pairwise symmetric with a common variable form one group.
+    ///
+    /// # Returns
+    /// Groups of at least two variables, each sorted, in the order of their first variable.
+    pub fn symmetric_groups(&self) -> Vec<Vec<char>> {
+        let mut remaining: Vec<usize> = (0..self.variables.len()).collect();
+        let mut groups = Vec::new();
+
+        while let Some(&first) = remaining.first() {
+            let (group, rest): (Vec<usize>, Vec<usize>) = remaining
+                .into_iter()
+                .partition(|&index| index == first || self.is_symmetric_in(first, index, false));
+            remaining = rest;
+
+            if group.len() > 1 {
+                groups.push(group.iter().map(|&index| self.variables[index]).collect());
+            }
+        }
+
+        groups
+    }
+
+    /// Computes the NPN canonical form of the function by trying every permutation and negation of the inputs and
+    /// both polarities of the output, keeping the smallest table.
+    ///
+    /// Don't-care rows are treated as their results.
+    ///
+    /// # Errors
+    /// Returns [NpnError::TooManyVariables] if the table has more than [NPN_MAX_VARIABLES] variables.
+    pub fn npn_canonical(&self) -> Result<NpnCanonical, NpnError> {
+        let length = self.variables.len();
+        if length > NPN_MAX_VARIABLES {
+            return Err(NpnError::TooManyVariables {
+                variables: length,
+                maximum: NPN_MAX_VARIABLES,
+            });
+        }
+
+        let rows_length = 1usize << length;
+        let full = u64::MAX >> (u64::BITS as usize - rows_length);
+        let table: u64 = (0..rows_length)
+            .filter(|&row| self.rows[row].result)
+            .map(|row| 1 << row)
+            .sum();
+
+        let mut best: Option<(u64, NpnTransform)> = None;
+        for permutation in permutations(length) {
+            for input_negations in 0..rows_length as u64 {
+                let transformed: u64 = (0..rows_length)
+                    .filter(|&row| {
+                        let source: usize = (0..length)
+                            .filter(|&index| (row as u64 ^ input_negations) >> index & 1 == 1)
+                            .map(|index| 1 << permutation[index])
+                            .sum();
+                        table >> source & 1 == 1
+                    })
+                    .map(|row| 1 << row)
+                    .sum();
+
+                for (candidate, output_negation) in
+                    [(transformed, false), (!transformed & full, true)]
+                {
+                    if best.as_ref().is_none_or(|(table, _)| candidate < *table) {
+                        best = Some((
+                            candidate,
+                            NpnTransform {
+                                permutation: permutation.clone(),
+                                input_negations,
+                                output_negation,
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+
+        let (table, transform) = best.unwrap();
+        Ok(NpnCanonical {
+            variables: self.variables.clone(),
+            table,
+            transform,
+        })
+    }
+
+    /// Checks whether two functions are the same up to permutation and negation of inputs and negation of the output.
+    ///
+    /// Functions with different numbers of variables are never NPN-equivalent.
+    ///
+    /// # Errors
+    /// Returns [NpnError::TooManyVariables] if a table has more than [NPN_MAX_VARIABLES] variables.
+    pub fn is_npn_equivalent(&self, other: &TruthTable) -> Result<bool, NpnError> {
+        Ok(self.variables.len() == other.variables.len()
+            && self.npn_canonical()?.table == other.npn_canonical()?.table)
+    }
+}
+
+impl Expression {
+    /// Computes the NPN canonical form of the function of the expression over its variables.
+    ///
+    /// # Errors
+    /// Returns [NpnError::TooManyVariables] if the expression has more than [NPN_MAX_VARIABLES] variables.
+    pub fn npn_canonical(&self) -> Result<NpnCanonical, NpnError> {
+        TruthTable::from(self).npn_canonical()
+    }
+}
+
+/// Generates all permutations of `0..length`.
+fn permutations(length: usize) -> Vec<Vec<usize>> {
+    if length == 0 {
+        return vec![vec![]];
+    }
+
+    permutations(length - 1)
+        .into_iter()
+        .flat_map(|shorter| {
+            (0..length).map(move |position| {
+                let mut permutation: Vec<usize> = shorter
+                    .iter()
+                    .map(|&index| index + usize::from(index >= position))
+                    .collect();
+                permutation.insert(0, position);
+                permutation
+            })
+        })
+        .collect()
+}
@@ -48,3 +48,27 @@ pub fn parse(input: &str) -> Result<Pairs<'_, Rule>, ParserError> {
         Grammar::parse(Rule::file, input).map_err(|e| ParserError::PestError(Box::new(e)))?;
     Ok(pairs)
 }
+
+/// Parses an input string of arguments into `Pairs<Rule>`.
+///
+/// It checks for empty error and then uses [Grammar] to parse the input with the `arguments` rule.
+///
+/// # Arguments
+/// * `input` - The string to parse, with a `premises:` line followed by a `conclude:` line for every argument.
+///
+/// # Returns
+/// A [Result] containing successful `Pairs<'_, Rule>` or a [ParserError].
+///
+/// # Errors
+/// Returns [ParserError::PestError] if Pest fails to parse the input string.
+///
+/// Returns [ParserError::EmptyInputError] if `input` is empty.
+pub fn parse_arguments(input: &str) -> Result<Pairs<'_, Rule>, ParserError> {
+    if input.is_empty() {
+        return Err(ParserError::EmptyInputError);
+    }
+
+    let pairs =
+        Grammar::parse(Rule::arguments, input).map_err(|e| ParserError::PestError(Box::new(e)))?;
+    Ok(pairs)
+}
@@ -0,0 +1,168 @@
+use crate::ast::Expression;
+use std::collections::HashMap;
+
+impl Expression {
+    /// Evaluates a logical expression with a subset of its variables and returns the residual expression.
+    ///
+    /// Variables present in `variables` are replaced with constants, which are then folded through every operator.
+    /// Variables that are not assigned are left untouched.
+    ///
+    /// # Arguments
+    /// * `variables` - `HashMap<char, bool>` that maps some identifiers to their boolean values.
+    ///
+    /// # Returns
+    /// A simplified [Expression], which is [Expression::Constant] if the result no longer depends on any variable.
+    pub fn partial_evaluate(&self, variables: &HashMap<char, bool>) -> Expression {
+        match self {
+            Expression::Constant(value) => Expression::Constant(*value),
+            Expression::Identifier(iden) => match variables.get(iden) {
+                Some(&value) => Expression::Constant(value),
+                None => Expression::Identifier(*iden),
+            },
+            Expression::Not(expr) => negate(expr.partial_evaluate(variables)),
+            Expression::And(left, right) => and(
+                left.partial_evaluate(variables),
+                right.partial_evaluate(variables),
+            ),
+            Expression::Nand(left, right) => nand(
+                left.partial_evaluate(variables),
+                right.partial_evaluate(variables),
+            ),
+            Expression::Or(left, right) => or(
+                left.partial_evaluate(variables),
+                right.partial_evaluate(variables),
+            ),
+            Expression::Nor(left, right) => nor(
+                left.partial_evaluate(variables),
+                right.partial_evaluate(variables),
+            ),
+            Expression::Xor(left, right) => xor(
+                left.partial_evaluate(variables),
+                right.partial_evaluate(variables),
+            ),
+            Expression::Xnor(left, right) => xnor(
+                left.partial_evaluate(variables),
+                right.partial_evaluate(variables),
+            ),
+            Expression::Implies(left, right) => implies(
+                left.partial_evaluate(variables),
+                right.partial_evaluate(variables),
+            ),
+        }
+    }
+
+    /// Computes the Shannon cofactor of the expression with respect to one variable.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier to fix.
+    /// * `value` - The value the identifier is fixed to.
+    ///
+    /// # Returns
+    /// The residual [Expression] with `variable` replaced by `value`.
+    pub fn cofactor(&self, variable: char, value: bool) -> Expression {
+        self.partial_evaluate(&HashMap::from([(variable, value)]))
+    }
+
+    /// Computes the positive cofactor, i.e. the expression with `variable` set to `1`.
+    pub fn positive_cofactor(&self, variable: char) -> Expression {
+        self.cofactor(variable, true)
+    }
+
+    /// Computes the negative cofactor, i.e. the expression with `variable` set to `0`.
+    pub fn negative_cofactor(&self, variable: char) -> Expression {
+        self.cofactor(variable, false)
+    }
+}
+
+/// Negates an expression, folding constants and removing double negations.
+fn negate(expression: Expression) -> Expression {
+    match expression {
+        Expression::Constant(value) => Expression::Constant(!value),
+        Expression::Not(expr) => *expr,
+        expr => Expression::Not(Box::new(expr)),
+    }
+}
+
+/// Folds an AND operation.
+fn and(left: Expression, right: Expression) -> Expression {
+    match (left, right) {
+        (Expression::Constant(false), _) | (_, Expression::Constant(false)) => {
+            Expression::Constant(false)
+        }
+        (Expression::Constant(true), expr) | (expr, Expression::Constant(true)) => expr,
+        (left, right) => Expression::And(Box::new(left), Box::new(right)),
+    }
+}
+
+/// Folds a NAND operation.
+fn nand(left: Expression, right: Expression) -> Expression {
+    match (left, right) {
+        (Expression::Constant(false), _) | (_, Expression::Constant(false)) => {
+            Expression::Constant(true)
+        }
+        (Expression::Constant(true), expr) | (expr, Expression::Constant(true)) => negate(expr),
+        (left, right) => Expression::Nand(Box::new(left), Box::new(right)),
+    }
+}
+
+/// Folds an OR operation.
+fn or(left: Expression, right: Expression) -> Expression {
+    match (left, right) {
+        (Expression::Constant(true), _) | (_, Expression::Constant(true)) => {
+            Expression::Constant(true)
+        }
+        (Expression::Constant(false), expr) | (expr, Expression::Constant(false)) => expr,
+        (left, right) => Expression::Or(Box::new(left), Box::new(right)),
+    }
+}
+
+/// Folds a NOR operation.
+fn nor(left: Expression, right: Expression) -> Expression {
+    match (left, right) {
+        (Expression::Constant(true), _) | (_, Expression::Constant(true)) => {
+            Expression::Constant(false)
+        }
+        (Expression::Constant(false), expr) | (expr, Expression::Constant(false)) => negate(expr),
+        (left, right) => Expression::Nor(Box::new(left), Box::new(right)),
+    }
+}
+
+/// Folds a XOR operation.
+fn xor(left: Expression, right: Expression) -> Expression {
+    match (left, right) {
+        (Expression::Constant(value), expr) | (expr, Expression::Constant(value)) => {
+            if value {
+                negate(expr)
+            } else {
+                expr
+            }
+        }
+        (left, right) => Expression::Xor(Box::new(left), Box::new(right)),
+    }
+}
+
+/// Folds a XNOR operation.
+fn xnor(left: Expression, right: Expression) -> Expression {
+    match (left, right) {
+        (Expression::Constant(value), expr) | (expr, Expression::Constant(value)) => {
+            if value {
+                expr
+            } else {
+                negate(expr)
+            }
+        }
+        (left, right) => Expression::Xnor(Box::new(left), Box::new(right)),
+    }
+}
+
+/// Folds an implication.
+fn implies(left: Expression, right: Expression) -> Expression {
+    match (left, right) {
+        (Expression::Constant(false), _) | (_, Expression::Constant(true)) => {
+            Expression::Constant(true)
+        }
+        (Expression::Constant(true), expr) => expr,
+        (expr, Expression::Constant(false)) => negate(expr),
+        (left, right) => Expression::Implies(Box::new(left), Box::new(right)),
+    }
+}
@@ -0,0 +1,124 @@
+use crate::ast::Expression;
+use crate::truth_table::TruthTable;
+use std::fmt::{Display, Formatter};
+
+/// One of Post's five maximal classes of Boolean functions, each closed under composition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PostClass {
+    /// Functions that are `0` when all inputs are `0`.
+    PreservesZero,
+    /// Functions that are `1` when all inputs are `1`.
+    PreservesOne,
+    /// Functions whose output is negated by negating all inputs.
+    SelfDual,
+    /// Functions whose output never falls when an input rises.
+    Monotone,
+    /// Functions whose algebraic normal form has no monomial of degree above `1`, i.e. XORs of variables and constants.
+    Affine,
+}
+
+impl PostClass {
+    /// All five classes, in the order they are reported.
+    pub const ALL: [PostClass; 5] = [
+        PostClass::PreservesZero,
+        PostClass::PreservesOne,
+        PostClass::SelfDual,
+        PostClass::Monotone,
+        PostClass::Affine,
+    ];
+}
+
+impl Display for PostClass {
+    /// Formats a [PostClass] by its usual name.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PostClass::PreservesZero => write!(f, "T0-preserving"),
+            PostClass::PreservesOne => write!(f, "T1-preserving"),
+            PostClass::SelfDual => write!(f, "self-dual"),
+            PostClass::Monotone => write!(f, "monotone"),
+            PostClass::Affine => write!(f, "affine"),
+        }
+    }
+}
+
+impl TruthTable {
+    /// Checks whether the result of the row with all variables `0` is `0`.
+    pub fn preserves_zero(&self) -> bool {
+        !self.rows[0].result
+    }
+
+    /// Checks whether the result of the row with all variables `1` is `1`.
+    pub fn preserves_one(&self) -> bool {
+        self.rows[self.rows.len() - 1].result
+    }
+
+    /// Checks whether every row and the row with all values negated have different results.
+    pub fn is_self_dual(&self) -> bool {
+        let last = self.rows.len() - 1;
+        (0..self.rows.len()).all(|row| self.rows[row].result != self.rows[last ^ row].result)
+    }
+
+    /// Checks whether setting any variable of any row from `0` to `1` never changes the result from `1` to `0`.
+    pub fn is_monotone(&self) -> bool {
+        (0..self.rows.len()).all(|row| {
+            (0..self.variables.len())
+                .map(|index| 1 << index)
+                .filter(|bit| row & bit == 0)
+                .all(|bit| !self.rows[row].result || self.rows[row | bit].result)
+        })
+    }
+
+    /// Checks whether the algebraic degree of the function is at most `1`.
+    pub fn is_affine(&self) -> bool {
+        self.to_anf().degree() <= 1
+    }
+
+    /// Checks whether the function of the table belongs to one of Post's classes.
+    ///
+    /// Don't-care rows are treated as their results.
+    pub fn in_post_class(&self, class: PostClass) -> bool {
+        match class {
+            PostClass::PreservesZero => self.preserves_zero(),
+            PostClass::PreservesOne => self.preserves_one(),
+            PostClass::SelfDual => self.is_self_dual(),
+            PostClass::Monotone => self.is_monotone(),
+            PostClass::Affine => self.is_affine(),
+        }
+    }
+
+    /// Collects Post's classes the function of the table belongs to.
+    pub fn post_classes(&self) -> Vec<PostClass> {
+        PostClass::ALL
+            .into_iter()
+            .filter(|&class| self.in_post_class(class))
+            .collect()
+    }
+}
+
+impl Expression {
+    /// Collects Post's classes the function of the expression belongs to.
+    pub fn post_classes(&self) -> Vec<PostClass> {
+        TruthTable::from(self).post_classes()
+    }
+}
+
+/// Collects Post's classes that contain every function of a set of connectives.
+///
+/// Every function built from the connectives stays in these classes, so they are the reasons why the set is not
+/// functionally complete.
+///
+/// # Returns
+/// The common classes in the order of [PostClass::ALL]; all of them for an empty set.
+pub fn common_post_classes(tables: &[TruthTable]) -> Vec<PostClass> {
+    PostClass::ALL
+        .into_iter()
+        .filter(|&class| tables.iter().all(|table| table.in_post_class(class)))
+        .collect()
+}
+
+/// Checks whether a set of connectives is functionally complete, i.e. every Boolean function can be built from them.
+///
+/// By Post's criterion this holds exactly when, for each of the five classes, some connective does not belong to it.
+pub fn is_functionally_complete(tables: &[TruthTable]) -> bool {
+    common_post_classes(tables).is_empty()
+}
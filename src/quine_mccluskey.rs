@@ -0,0 +1,227 @@
+use crate::ast::Expression;
+use crate::cube::Cube;
+use crate::dnf::Dnf;
+use crate::truth_table::TruthTable;
+use std::collections::{BTreeSet, HashSet};
+
+/// The result of a two-level minimization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Minimization {
+    /// A sorted list of unique variables; bit `i` of every cube refers to the variable with index `i`.
+    pub variables: Vec<char>,
+    /// All prime implicants of the function.
+    pub prime_implicants: Vec<Cube>,
+    /// Prime implicants that are the only ones covering some minterm.
+    pub essential_prime_implicants: Vec<Cube>,
+    /// The chosen prime implicants whose sum is a minimal sum of products.
+    pub cover: Vec<Cube>,
+}
+
+impl Minimization {
+    /// Converts the cover into a [Dnf].
+    pub fn to_dnf(&self) -> Dnf {
+        Dnf {
+            terms: self
+                .cover
+                .iter()
+                .map(|cube| cube.to_term(&self.variables))
+                .collect(),
+        }
+    }
+
+    /// Converts the cover into an [Expression].
+    pub fn to_expression(&self) -> Expression {
+        self.to_dnf().to_expression()
+    }
+
+    /// Returns the total number of literals of the cover.
+    pub fn literal_count(&self) -> usize {
+        self.cover.iter().map(Cube::literal_count).sum()
+    }
+}
+
+/// Minimizes a function with the Quine–McCluskey method.
+///
+/// Prime implicants are generated by repeatedly merging cubes, essential prime implicants are selected first,
+/// and the remaining cyclic core is covered exactly using Petrick's method, preferring fewer terms and then fewer literals.
+///
+/// # Arguments
+/// * `variables` - The variables of the function; bit `i` of a minterm is the value of `variables[i]`.
+/// * `minterms` - Row numbers where the function is `1`.
+/// * `dont_cares` - Row numbers where the value of the function does not matter.
+///
+/// # Returns
+/// A [Minimization] with all prime implicants, the essential ones and a minimal cover.
+pub fn minimize(variables: &[char], minterms: &[usize], dont_cares: &[usize]) -> Minimization {
+    let variables_length = variables.len();
+    let prime_implicants = prime_implicants(variables_length, minterms, dont_cares);
+
+    let mut uncovered: BTreeSet<usize> = minterms.iter().copied().collect();
+    let mut essential_prime_implicants = Vec::new();
+    for &minterm in minterms {
+        let mut covering = prime_implicants
+            .iter()
+            .filter(|cube| cube.contains_minterm(minterm));
+        if let (Some(&cube), None) = (covering.next(), covering.next())
+            && !essential_prime_implicants.contains(&cube)
+        {
+            essential_prime_implicants.push(cube);
+        }
+    }
+    essential_prime_implicants.sort();
+
+    uncovered.retain(|&minterm| {
+        !essential_prime_implicants
+            .iter()
+            .any(|cube| cube.contains_minterm(minterm))
+    });
+
+    let candidates: Vec<Cube> = prime_implicants
+        .iter()
+        .filter(|cube| {
+            !essential_prime_implicants.contains(cube)
+                && uncovered
+                    .iter()
+                    .any(|&minterm| cube.contains_minterm(minterm))
+        })
+        .copied()
+        .collect();
+
+    let mut cover = essential_prime_implicants.clone();
+    cover.extend(petrick(&candidates, &uncovered));
+    cover.sort();
+
+    Minimization {
+        variables: variables.to_vec(),
+        prime_implicants,
+        essential_prime_implicants,
+        cover,
+    }
+}
+
+/// Generates all prime implicants of a function.
+///
+/// # Arguments
+/// * `variables_length` - The number of variables.
+/// * `minterms` - Row numbers where the function is `1`.
+/// * `dont_cares` - Row numbers where the value of the function does not matter.
+///
+/// # Returns
+/// A sorted `Vec<Cube>` of prime implicants covering at least one minterm.
+pub fn prime_implicants(
+    variables_length: usize,
+    minterms: &[usize],
+    dont_cares: &[usize],
+) -> Vec<Cube> {
+    let mut current: HashSet<Cube> = minterms
+        .iter()
+        .chain(dont_cares)
+        .map(|&minterm| Cube::minterm(minterm, variables_length))
+        .collect();
+    let mut primes: Vec<Cube> = Vec::new();
+
+    while !current.is_empty() {
+        let mut merged = HashSet::new();
+        let mut next = HashSet::new();
+
+        for cube in &current {
+            let mut zero_bits = cube.mask & !cube.values;
+            while zero_bits != 0 {
+                let bit = zero_bits & zero_bits.wrapping_neg();
+                zero_bits &= !bit;
+
+                let partner = Cube::new(cube.values | bit, cube.mask);
+                if current.contains(&partner) {
+                    merged.insert(*cube);
+                    merged.insert(partner);
+                    next.insert(cube.combine(&partner).unwrap());
+                }
+            }
+        }
+
+        primes.extend(
+            current
+                .iter()
+                .filter(|cube| !merged.contains(cube))
+                .copied(),
+        );
+        current = next;
+    }
+
+    primes.retain(|cube| {
+        minterms
+            .iter()
+            .any(|&minterm| cube.contains_minterm(minterm))
+    });
+    primes.sort();
+    primes
+}
+
+/// Selects a minimum set of candidates covering all minterms using Petrick's method.
+///
+/// The product of sums "some candidate covering each minterm" is multiplied out with absorption, and the product with the fewest
+/// candidates, then the fewest literals, is chosen.
+///
+/// # Arguments
+/// * `candidates` - Prime implicants that may be selected.
+/// * `minterms` - Minterms that must be covered.
+fn petrick(candidates: &[Cube], minterms: &BTreeSet<usize>) -> Vec<Cube> {
+    let mut products: Vec<BTreeSet<usize>> = vec![BTreeSet::new()];
+
+    for &minterm in minterms {
+        let covering: Vec<usize> = (0..candidates.len())
+            .filter(|&index| candidates[index].contains_minterm(minterm))
+            .collect();
+
+        let mut expanded: Vec<BTreeSet<usize>> = Vec::new();
+        for product in &products {
+            if covering.iter().any(|index| product.contains(index)) {
+                expanded.push(product.clone());
+                continue;
+            }
+            for &index in &covering {
+                let mut extended = product.clone();
+                extended.insert(index);
+                expanded.push(extended);
+            }
+        }
+
+        expanded.sort_by_key(BTreeSet::len);
+        products = Vec::with_capacity(expanded.len());
+        for product in expanded {
+            if !products.iter().any(|kept| kept.is_subset(&product)) {
+                products.push(product);
+            }
+        }
+    }
+
+    products
+        .into_iter()
+        .min_by_key(|product| {
+            let literals: usize = product
+                .iter()
+                .map(|&index| candidates[index].literal_count())
+                .sum();
+            (product.len(), literals)
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .map(|index| candidates[index])
+        .collect()
+}
+
+impl TruthTable {
+    /// Minimizes the function of the table into a minimal sum of products with the Quine–McCluskey method.
+    ///
+    /// Don't-care rows are used to form larger implicants but never need to be covered.
+    pub fn minimize(&self) -> Minimization {
+        minimize(&self.variables, &self.minterms(), &self.dont_cares())
+    }
+}
+
+impl Expression {
+    /// Minimizes the expression into a minimal sum of products with the Quine–McCluskey method.
+    pub fn minimize(&self) -> Minimization {
+        TruthTable::from(self).minimize()
+    }
+}
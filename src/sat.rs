@@ -0,0 +1,550 @@
+use crate::assignment::Assignment;
+use crate::ast::Expression;
+use crate::cnf::Cnf;
+use crate::literal::Variable;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// The number of conflicts between restarts, multiplied by the Luby sequence.
+const RESTART_INTERVAL: usize = 100;
+
+/// The factor by which variable activities decay after every conflict.
+const ACTIVITY_DECAY: f64 = 0.95;
+
+/// The activity above which all activities are scaled down to avoid overflow.
+const ACTIVITY_LIMIT: f64 = 1e100;
+
+/// A literal of the solver: the index of its variable times two, plus one if it is negated.
+type Lit = usize;
+
+/// A satisfying assignment of the input variables found by the [Solver].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Model {
+    /// A sorted list of the input variables known to the solver.
+    pub variables: Vec<char>,
+    /// The value of each variable.
+    pub values: Vec<bool>,
+}
+
+impl Assignment for Model {
+    fn value(&self, variable: char) -> Option<bool> {
+        self.variables
+            .binary_search(&variable)
+            .ok()
+            .map(|index| self.values[index])
+    }
+}
+
+impl Display for Model {
+    /// Formats a [Model] as `A = 1, B = 0`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.variables.is_empty() {
+            return write!(f, "no variables");
+        }
+
+        let assignments: Vec<String> = self
+            .variables
+            .iter()
+            .zip(&self.values)
+            .map(|(variable, &value)| format!("{} = {}", variable, u8::from(value)))
+            .collect();
+        write!(f, "{}", assignments.join(", "))
+    }
+}
+
+/// An incremental conflict-driven clause learning SAT solver.
+///
+/// Clauses are propagated with two watched literals, every conflict is analyzed down to its first unique implication
+/// point and the learned clause is kept, decisions follow the most active variable with its last value, and the search
+/// restarts after a number of conflicts following the Luby sequence.
+///
+/// Clauses can be added between calls to [Solver::solve_with_assumptions], and learned clauses are kept across calls.
+#[derive(Debug, Clone)]
+pub struct Solver {
+    /// The solver variable of every input variable.
+    indices: HashMap<char, usize>,
+    /// Original and learned clauses with at least two literals; the first two literals are watched.
+    clauses: Vec<Vec<Lit>>,
+    /// For every literal, the clauses watching it.
+    watches: Vec<Vec<usize>>,
+    /// The current value of every variable.
+    values: Vec<Option<bool>>,
+    /// The decision level at which every assigned variable was assigned.
+    levels: Vec<usize>,
+    /// The clause that implied every assigned variable, or `None` for decisions and top level facts.
+    reasons: Vec<Option<usize>>,
+    /// Assigned literals in assignment order.
+    trail: Vec<Lit>,
+    /// The length of the trail when every decision level started.
+    trail_limits: Vec<usize>,
+    /// The number of trail literals whose consequences have been propagated.
+    propagated: usize,
+    /// The activity of every variable, bumped when it takes part in a conflict.
+    activity: Vec<f64>,
+    /// The amount added to the activity of a bumped variable.
+    activity_increment: f64,
+    /// The last value of every variable, reused when deciding it again.
+    phases: Vec<bool>,
+    /// Helper marks of variables during conflict analysis.
+    seen: Vec<bool>,
+    /// Set once the clauses are unsatisfiable regardless of assumptions.
+    inconsistent: bool,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solver {
+    /// Creates a [Solver] without variables and clauses.
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            clauses: Vec::new(),
+            watches: Vec::new(),
+            values: Vec::new(),
+            levels: Vec::new(),
+            reasons: Vec::new(),
+            trail: Vec::new(),
+            trail_limits: Vec::new(),
+            propagated: 0,
+            activity: Vec::new(),
+            activity_increment: 1.0,
+            phases: Vec::new(),
+            seen: Vec::new(),
+            inconsistent: false,
+        }
+    }
+
+    /// Adds an input variable, so that it is part of every model even if no clause mentions it.
+    pub fn declare(&mut self, variable: char) {
+        self.input_index(variable);
+    }
+
+    /// Adds the clauses of a formula.
+    ///
+    /// Input variables are shared with all other clauses, while the auxiliary variables of the formula are fresh,
+    /// so formulas of several Tseitin encodings can be added to the same solver.
+    pub fn add_cnf(&mut self, cnf: &Cnf) {
+        let mut auxiliaries: HashMap<usize, usize> = HashMap::new();
+
+        for clause in &cnf.clauses {
+            let literals = clause
+                .literals
+                .iter()
+                .map(|literal| {
+                    let index = match literal.variable {
+                        Variable::Input(iden) => self.input_index(iden),
+                        Variable::Auxiliary(number) => match auxiliaries.get(&number) {
+                            Some(&index) => index,
+                            None => {
+                                let index = self.new_variable();
+                                auxiliaries.insert(number, index);
+                                index
+                            }
+                        },
+                    };
+                    2 * index + usize::from(!literal.positive)
+                })
+                .collect();
+            self.add_clause(literals);
+        }
+    }
+
+    /// Adds an expression as a constraint that must be `1`, using its Tseitin encoding.
+    pub fn add_expression(&mut self, expression: &Expression) {
+        for variable in expression.variables() {
+            self.declare(variable);
+        }
+        self.add_cnf(&expression.to_tseitin_cnf());
+    }
+
+    /// Searches for a model of all added clauses.
+    ///
+    /// # Returns
+    /// A [Model] of the input variables, or `None` if the clauses are unsatisfiable.
+    pub fn solve(&mut self) -> Option<Model> {
+        self.solve_with_assumptions(&[])
+    }
+
+    /// Searches for a model of all added clauses in which the assumed variables have the given values.
+    ///
+    /// Assumptions only hold for this call, so the solver can be queried again under different assumptions.
+    ///
+    /// # Arguments
+    /// * `assumptions` - Pairs of input variables and their assumed values.
+    ///
+    /// # Returns
+    /// A [Model] of the input variables, or `None` if there is no model under the assumptions.
+    pub fn solve_with_assumptions(&mut self, assumptions: &[(char, bool)]) -> Option<Model> {
+        let assumptions: Vec<Lit> = assumptions
+            .iter()
+            .map(|&(variable, value)| 2 * self.input_index(variable) + usize::from(!value))
+            .collect();
+        if self.inconsistent {
+            return None;
+        }
+
+        let model = self.search(&assumptions).then(|| self.model());
+        self.backtrack(0);
+        model
+    }
+
+    /// Helper method that returns the solver variable of an input variable, creating it if needed.
+    fn input_index(&mut self, variable: char) -> usize {
+        match self.indices.get(&variable) {
+            Some(&index) => index,
+            None => {
+                let index = self.new_variable();
+                self.indices.insert(variable, index);
+                index
+            }
+        }
+    }
+
+    /// Helper method that creates a new unassigned variable.
+    fn new_variable(&mut self) -> usize {
+        self.watches.push(Vec::new());
+        self.watches.push(Vec::new());
+        self.values.push(None);
+        self.levels.push(0);
+        self.reasons.push(None);
+        self.activity.push(0.0);
+        self.phases.push(false);
+        self.seen.push(false);
+        self.values.len() - 1
+    }
+
+    /// Helper method that adds a clause at the top level, dropping literals that are already false.
+    fn add_clause(&mut self, mut literals: Vec<Lit>) {
+        if self.inconsistent {
+            return;
+        }
+
+        literals.sort();
+        literals.dedup();
+        if literals.windows(2).any(|pair| pair[0] ^ 1 == pair[1])
+            || literals
+                .iter()
+                .any(|&literal| self.literal_value(literal) == Some(true))
+        {
+            return;
+        }
+        literals.retain(|&literal| self.literal_value(literal).is_none());
+
+        match literals.len() {
+            0 => self.inconsistent = true,
+            1 => self.enqueue(literals[0], None),
+            _ => {
+                self.attach(literals);
+            }
+        }
+    }
+
+    /// Helper method that stores a clause and watches its first two literals.
+    fn attach(&mut self, literals: Vec<Lit>) -> usize {
+        let index = self.clauses.len();
+        self.watches[literals[0]].push(index);
+        self.watches[literals[1]].push(index);
+        self.clauses.push(literals);
+        index
+    }
+
+    /// Helper method that returns the current value of a literal.
+    fn literal_value(&self, literal: Lit) -> Option<bool> {
+        value_of(&self.values, literal)
+    }
+
+    /// Helper method that returns the current decision level.
+    fn decision_level(&self) -> usize {
+        self.trail_limits.len()
+    }
+
+    /// Helper method that makes a literal true at the current decision level.
+    fn enqueue(&mut self, literal: Lit, reason: Option<usize>) {
+        let variable = literal >> 1;
+        self.values[variable] = Some(literal & 1 == 0);
+        self.levels[variable] = self.decision_level();
+        self.reasons[variable] = reason;
+        self.trail.push(literal);
+    }
+
+    /// Helper method that undoes all assignments above a decision level, remembering their values as phases.
+    fn backtrack(&mut self, level: usize) {
+        if self.decision_level() <= level {
+            return;
+        }
+
+        let limit = self.trail_limits[level];
+        for &literal in &self.trail[limit..] {
+            let variable = literal >> 1;
+            self.phases[variable] = literal & 1 == 0;
+            self.values[variable] = None;
+            self.reasons[variable] = None;
+        }
+        self.trail.truncate(limit);
+        self.trail_limits.truncate(level);
+        self.propagated = limit;
+    }
+
+    /// Helper method that assigns the literals implied by unit clauses until a fixpoint or a conflict.
+    ///
+    /// Only clauses watching a literal that became false are visited. Each of them either finds another non-false
+    /// literal to watch, is already satisfied by its other watched literal, implies that literal, or is in conflict.
+    ///
+    /// # Returns
+    /// The index of a clause with all literals false, or `None` if there is no conflict.
+    fn propagate(&mut self) -> Option<usize> {
+        while self.propagated < self.trail.len() {
+            let false_literal = self.trail[self.propagated] ^ 1;
+            self.propagated += 1;
+
+            let mut watching = std::mem::take(&mut self.watches[false_literal]);
+            let mut kept = 0;
+            let mut conflict = None;
+
+            let mut position = 0;
+            while position < watching.len() {
+                let clause_index = watching[position];
+                position += 1;
+
+                let clause = &mut self.clauses[clause_index];
+                if clause[0] == false_literal {
+                    clause.swap(0, 1);
+                }
+                let first = clause[0];
+                if value_of(&self.values, first) == Some(true) {
+                    watching[kept] = clause_index;
+                    kept += 1;
+                    continue;
+                }
+
+                if let Some(replacement) = (2..clause.len())
+                    .find(|&index| value_of(&self.values, clause[index]) != Some(false))
+                {
+                    clause.swap(1, replacement);
+                    self.watches[clause[1]].push(clause_index);
+                    continue;
+                }
+
+                watching[kept] = clause_index;
+                kept += 1;
+                if value_of(&self.values, first) == Some(false) {
+                    conflict = Some(clause_index);
+                    while position < watching.len() {
+                        watching[kept] = watching[position];
+                        kept += 1;
+                        position += 1;
+                    }
+                } else {
+                    self.enqueue(first, Some(clause_index));
+                }
+            }
+
+            watching.truncate(kept);
+            self.watches[false_literal] = watching;
+            if conflict.is_some() {
+                return conflict;
+            }
+        }
+
+        None
+    }
+
+    /// Helper method that derives a learned clause from a conflict by resolving it with the reasons of literals of the
+    /// current decision level, until only one of them, the first unique implication point, is left.
+    ///
+    /// # Returns
+    /// The learned clause with the negated implication point first and a literal of the highest remaining level second,
+    /// and that level, to which the search jumps back.
+    fn analyze(&mut self, conflict: usize) -> (Vec<Lit>, usize) {
+        let mut learned: Vec<Lit> = vec![0];
+        let mut pending = 0;
+        let mut position = self.trail.len();
+        let mut clause_index = conflict;
+        let mut resolved = false;
+
+        loop {
+            for index in usize::from(resolved)..self.clauses[clause_index].len() {
+                let literal = self.clauses[clause_index][index];
+                let variable = literal >> 1;
+                if !self.seen[variable] && self.levels[variable] > 0 {
+                    self.seen[variable] = true;
+                    self.bump(variable);
+                    if self.levels[variable] == self.decision_level() {
+                        pending += 1;
+                    } else {
+                        learned.push(literal);
+                    }
+                }
+            }
+
+            loop {
+                position -= 1;
+                if self.seen[self.trail[position] >> 1] {
+                    break;
+                }
+            }
+            let literal = self.trail[position];
+            self.seen[literal >> 1] = false;
+            pending -= 1;
+
+            if pending == 0 {
+                learned[0] = literal ^ 1;
+                break;
+            }
+            clause_index = self.reasons[literal >> 1].unwrap();
+            resolved = true;
+        }
+
+        for &literal in &learned[1..] {
+            self.seen[literal >> 1] = false;
+        }
+
+        let level = match (1..learned.len()).max_by_key(|&index| self.levels[learned[index] >> 1]) {
+            Some(index) => {
+                learned.swap(1, index);
+                self.levels[learned[1] >> 1]
+            }
+            None => 0,
+        };
+
+        (learned, level)
+    }
+
+    /// Helper method that increases the activity of a variable taking part in a conflict.
+    fn bump(&mut self, variable: usize) {
+        self.activity[variable] += self.activity_increment;
+        if self.activity[variable] > ACTIVITY_LIMIT {
+            for activity in &mut self.activity {
+                *activity /= ACTIVITY_LIMIT;
+            }
+            self.activity_increment /= ACTIVITY_LIMIT;
+        }
+    }
+
+    /// Helper method that chooses the next decision: the most active unassigned variable with its last value.
+    fn pick_branch(&self) -> Option<Lit> {
+        (0..self.values.len())
+            .filter(|&variable| self.values[variable].is_none())
+            .max_by(|&left, &right| self.activity[left].total_cmp(&self.activity[right]))
+            .map(|variable| 2 * variable + usize::from(!self.phases[variable]))
+    }
+
+    /// Helper method that runs the CDCL loop, deciding the assumptions first.
+    ///
+    /// # Returns
+    /// `true` if all variables are assigned without conflict, `false` if there is no model under the assumptions.
+    fn search(&mut self, assumptions: &[Lit]) -> bool {
+        let mut restarts = 0;
+        let mut conflicts_left = RESTART_INTERVAL * luby(restarts);
+
+        loop {
+            if let Some(conflict) = self.propagate() {
+                if self.decision_level() == 0 {
+                    self.inconsistent = true;
+                    return false;
+                }
+
+                let (learned, level) = self.analyze(conflict);
+                self.backtrack(level);
+                if learned.len() == 1 {
+                    self.enqueue(learned[0], None);
+                } else {
+                    let asserting = learned[0];
+                    let index = self.attach(learned);
+                    self.enqueue(asserting, Some(index));
+                }
+                self.activity_increment /= ACTIVITY_DECAY;
+                conflicts_left = conflicts_left.saturating_sub(1);
+                continue;
+            }
+
+            if conflicts_left == 0 {
+                restarts += 1;
+                conflicts_left = RESTART_INTERVAL * luby(restarts);
+                self.backtrack(0);
+                continue;
+            }
+
+            let decision = match assumptions.get(self.decision_level()) {
+                Some(&assumption) => match self.literal_value(assumption) {
+                    Some(true) => {
+                        self.trail_limits.push(self.trail.len());
+                        continue;
+                    }
+                    Some(false) => return false,
+                    None => assumption,
+                },
+                None => match self.pick_branch() {
+                    Some(literal) => literal,
+                    None => return true,
+                },
+            };
+            self.trail_limits.push(self.trail.len());
+            self.enqueue(decision, None);
+        }
+    }
+
+    /// Helper method that reads the model of the input variables from a complete assignment.
+    fn model(&self) -> Model {
+        let mut variables: Vec<char> = self.indices.keys().copied().collect();
+        variables.sort();
+        let values = variables
+            .iter()
+            .map(|variable| self.values[self.indices[variable]].unwrap_or(false))
+            .collect();
+
+        Model { variables, values }
+    }
+}
+
+/// Returns the value of a literal under the values of the variables.
+fn value_of(values: &[Option<bool>], literal: Lit) -> Option<bool> {
+    values[literal >> 1].map(|value| value == (literal & 1 == 0))
+}
+
+/// Returns the element of the Luby sequence `1, 1, 2, 1, 1, 2, 4, 1, ...` at an index counted from `0`.
+fn luby(mut index: usize) -> usize {
+    let mut size = 1;
+    let mut exponent = 0;
+    while size < index + 1 {
+        exponent += 1;
+        size = 2 * size + 1;
+    }
+
+    while size - 1 != index {
+        size = (size - 1) >> 1;
+        exponent -= 1;
+        index %= size;
+    }
+
+    1 << exponent
+}
+
+impl Expression {
+    /// Finds a satisfying assignment of the expression with the CDCL [Solver] on its Tseitin encoding.
+    ///
+    /// Unlike [Expression::is_satisfiable], this does not enumerate all `2^n` assignments.
+    ///
+    /// # Returns
+    /// A [Model] of the variables of the expression, or `None` if it is unsatisfiable.
+    pub fn solve(&self) -> Option<Model> {
+        self.solve_with_assumptions(&[])
+    }
+
+    /// Finds a satisfying assignment of the expression in which the assumed variables have the given values.
+    ///
+    /// # Arguments
+    /// * `assumptions` - Pairs of variables and their assumed values.
+    ///
+    /// # Returns
+    /// A [Model] of the variables of the expression and the assumptions, or `None` if there is none.
+    pub fn solve_with_assumptions(&self, assumptions: &[(char, bool)]) -> Option<Model> {
+        let mut solver = Solver::new();
+        solver.add_expression(self);
+        solver.solve_with_assumptions(assumptions)
+    }
+}
@@ -0,0 +1,375 @@
+use crate::ast::Expression;
+use std::fmt::{Display, Formatter};
+
+/// The maximum number of rewriting steps, a safeguard against rules that undo each other.
+const MAX_STEPS: usize = 1000;
+
+/// A law of Boolean algebra used by the simplifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Law {
+    /// `A NAND B = NOT (A AND B)`, `A NOR B = NOT (A OR B)`, `A XNOR B = NOT (A XOR B)`, `A -> B = NOT A OR B`.
+    Definition,
+    /// `NOT NOT A = A`.
+    DoubleNegation,
+    /// `NOT (A AND B) = NOT A OR NOT B`, `NOT (A OR B) = NOT A AND NOT B`.
+    DeMorgan,
+    /// `A AND 1 = A`, `A OR 0 = A`.
+    Identity,
+    /// `A AND 0 = 0`, `A OR 1 = 1`.
+    Domination,
+    /// `A AND NOT A = 0`, `A OR NOT A = 1`, `NOT 0 = 1`, `NOT 1 = 0`.
+    Complement,
+    /// `A AND A = A`, `A OR A = A`.
+    Idempotence,
+    /// `A OR (A AND B) = A`, `A AND (A OR B) = A`.
+    Absorption,
+    /// `A AND B OR NOT A AND C OR B AND C = A AND B OR NOT A AND C`, and its dual.
+    Consensus,
+    /// `A XOR 0 = A`, `A XOR 1 = NOT A`, `A XOR A = 0`, `A XOR NOT A = 1`.
+    XorIdentity,
+}
+
+/// A single rewriting step of a simplification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// The law applied in this step.
+    pub law: Law,
+    /// The subterm that was rewritten.
+    pub before: Expression,
+    /// The subterm it was rewritten into.
+    pub after: Expression,
+    /// The whole expression after this step.
+    pub result: Expression,
+}
+
+/// The result of a step-by-step simplification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simplification {
+    /// The applied steps, in order.
+    pub steps: Vec<Step>,
+    /// The simplified expression.
+    pub result: Expression,
+}
+
+impl Display for Law {
+    /// Formats a [Law] using its conventional name.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Law::Definition => "Definition",
+            Law::DoubleNegation => "Double negation",
+            Law::DeMorgan => "De Morgan",
+            Law::Identity => "Identity",
+            Law::Domination => "Domination",
+            Law::Complement => "Complement",
+            Law::Idempotence => "Idempotence",
+            Law::Absorption => "Absorption",
+            Law::Consensus => "Consensus",
+            Law::XorIdentity => "XOR identity",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Display for Step {
+    /// Formats a [Step] as the law followed by the rewritten subterm.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} => {}", self.law, self.before, self.after)
+    }
+}
+
+impl Expression {
+    /// Simplifies the expression with the laws of Boolean algebra.
+    ///
+    /// # Returns
+    /// An equivalent, usually smaller [Expression].
+    pub fn simplify(&self) -> Expression {
+        self.simplify_with_steps().result
+    }
+
+    /// Simplifies the expression with the laws of Boolean algebra, recording every step.
+    ///
+    /// The leftmost outermost subterm that some [Law] applies to is rewritten until no law applies.
+    /// NAND, NOR and XNOR are first rewritten by definition, and negations are only ever pushed inwards.
+    ///
+    /// # Returns
+    /// A [Simplification] with all steps and the simplified expression.
+    pub fn simplify_with_steps(&self) -> Simplification {
+        let mut steps = Vec::new();
+        let mut current = self.clone();
+
+        while steps.len() < MAX_STEPS {
+            let Some((law, before, after, result)) = rewrite(&current) else {
+                break;
+            };
+            steps.push(Step {
+                law,
+                before,
+                after,
+                result: result.clone(),
+            });
+            current = result;
+        }
+
+        Simplification {
+            steps,
+            result: current,
+        }
+    }
+}
+
+/// Rewrites the leftmost outermost subterm that a law applies to.
+///
+/// # Returns
+/// The applied law, the rewritten subterm before and after, and the whole rewritten expression, or `None` if no law applies.
+fn rewrite(expression: &Expression) -> Option<(Law, Expression, Expression, Expression)> {
+    if let Some((law, after)) = rewrite_node(expression) {
+        return Some((law, expression.clone(), after.clone(), after));
+    }
+
+    let (left, right) = match expression {
+        Expression::Constant(_) | Expression::Identifier(_) => return None,
+        Expression::Not(expr) => {
+            let (law, before, after, result) = rewrite(expr)?;
+            return Some((law, before, after, Expression::Not(Box::new(result))));
+        }
+        Expression::And(left, right)
+        | Expression::Nand(left, right)
+        | Expression::Or(left, right)
+        | Expression::Nor(left, right)
+        | Expression::Xor(left, right)
+        | Expression::Xnor(left, right)
+        | Expression::Implies(left, right) => (left, right),
+    };
+
+    if let Some((law, before, after, result)) = rewrite(left) {
+        return Some((
+            law,
+            before,
+            after,
+            with_operands(expression, result, (**right).clone()),
+        ));
+    }
+    let (law, before, after, result) = rewrite(right)?;
+    Some((
+        law,
+        before,
+        after,
+        with_operands(expression, (**left).clone(), result),
+    ))
+}
+
+/// Applies a law at the root of an expression.
+fn rewrite_node(expression: &Expression) -> Option<(Law, Expression)> {
+    match expression {
+        Expression::Nand(left, right) => Some((
+            Law::Definition,
+            negation(Expression::And(left.clone(), right.clone())),
+        )),
+        Expression::Nor(left, right) => Some((
+            Law::Definition,
+            negation(Expression::Or(left.clone(), right.clone())),
+        )),
+        Expression::Xnor(left, right) => Some((
+            Law::Definition,
+            negation(Expression::Xor(left.clone(), right.clone())),
+        )),
+        Expression::Implies(left, right) => Some((
+            Law::Definition,
+            Expression::Or(Box::new(negation((**left).clone())), right.clone()),
+        )),
+        Expression::Not(expr) => match &**expr {
+            Expression::Not(inner) => Some((Law::DoubleNegation, (**inner).clone())),
+            Expression::Constant(value) => Some((Law::Complement, Expression::Constant(!value))),
+            Expression::And(left, right) => Some((
+                Law::DeMorgan,
+                Expression::Or(
+                    Box::new(negation((**left).clone())),
+                    Box::new(negation((**right).clone())),
+                ),
+            )),
+            Expression::Or(left, right) => Some((
+                Law::DeMorgan,
+                Expression::And(
+                    Box::new(negation((**left).clone())),
+                    Box::new(negation((**right).clone())),
+                ),
+            )),
+            _ => None,
+        },
+        Expression::And(..) => rewrite_chain(expression, true),
+        Expression::Or(..) => rewrite_chain(expression, false),
+        Expression::Xor(left, right) => rewrite_xor(left, right),
+        Expression::Constant(_) | Expression::Identifier(_) => None,
+    }
+}
+
+/// Applies a law to a chain of AND (`conjunctive`) or OR operations, treating it as one n-ary operation.
+fn rewrite_chain(expression: &Expression, conjunctive: bool) -> Option<(Law, Expression)> {
+    let operands = flatten(expression, conjunctive);
+
+    if let Some(index) = operands
+        .iter()
+        .position(|operand| *operand == Expression::Constant(conjunctive))
+    {
+        return Some((Law::Identity, chain(without(&operands, index), conjunctive)));
+    }
+
+    if operands.contains(&Expression::Constant(!conjunctive)) {
+        return Some((Law::Domination, Expression::Constant(!conjunctive)));
+    }
+
+    if operands
+        .iter()
+        .any(|operand| operands.iter().any(|other| is_complement(operand, other)))
+    {
+        return Some((Law::Complement, Expression::Constant(!conjunctive)));
+    }
+
+    for (index, operand) in operands.iter().enumerate() {
+        if operands[..index].contains(operand) {
+            return Some((
+                Law::Idempotence,
+                chain(without(&operands, index), conjunctive),
+            ));
+        }
+    }
+
+    let groups: Vec<Vec<Expression>> = operands
+        .iter()
+        .map(|operand| flatten(operand, !conjunctive))
+        .collect();
+
+    for (index, group) in groups.iter().enumerate() {
+        let absorbed = groups.iter().enumerate().any(|(other, other_group)| {
+            other != index
+                && other_group.len() < group.len()
+                && other_group.iter().all(|operand| group.contains(operand))
+        });
+        if absorbed {
+            return Some((
+                Law::Absorption,
+                chain(without(&operands, index), conjunctive),
+            ));
+        }
+    }
+
+    for (first, first_group) in groups.iter().enumerate() {
+        for second_group in &groups[first + 1..] {
+            let Some(consensus) = consensus(first_group, second_group) else {
+                continue;
+            };
+            for (index, group) in groups.iter().enumerate() {
+                let is_parent = *group == *first_group || *group == *second_group;
+                if !is_parent && consensus.iter().all(|operand| group.contains(operand)) {
+                    return Some((
+                        Law::Consensus,
+                        chain(without(&operands, index), conjunctive),
+                    ));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Applies a XOR identity.
+fn rewrite_xor(left: &Expression, right: &Expression) -> Option<(Law, Expression)> {
+    let result = match (left, right) {
+        (Expression::Constant(false), expr) | (expr, Expression::Constant(false)) => expr.clone(),
+        (Expression::Constant(true), expr) | (expr, Expression::Constant(true)) => {
+            negation(expr.clone())
+        }
+        (left, right) if left == right => Expression::Constant(false),
+        (left, right) if is_complement(left, right) => Expression::Constant(true),
+        _ => return None,
+    };
+
+    Some((Law::XorIdentity, result))
+}
+
+/// Computes the consensus of two groups that clash in exactly one operand, e.g. `B AND C` for `A AND B` and `NOT A AND C`.
+fn consensus(first: &[Expression], second: &[Expression]) -> Option<Vec<Expression>> {
+    let mut clashes = first
+        .iter()
+        .filter(|operand| second.iter().any(|other| is_complement(operand, other)));
+    let clash = clashes.next()?;
+    if clashes.next().is_some() {
+        return None;
+    }
+
+    let mut consensus: Vec<Expression> = first
+        .iter()
+        .filter(|operand| *operand != clash)
+        .cloned()
+        .collect();
+    for operand in second {
+        if !is_complement(clash, operand) && !consensus.contains(operand) {
+            consensus.push(operand.clone());
+        }
+    }
+
+    Some(consensus)
+}
+
+/// Checks whether one expression is the negation of the other.
+fn is_complement(left: &Expression, right: &Expression) -> bool {
+    matches!(left, Expression::Not(expr) if **expr == *right)
+        || matches!(right, Expression::Not(expr) if **expr == *left)
+}
+
+/// Wraps an expression into a NOT operation.
+fn negation(expression: Expression) -> Expression {
+    Expression::Not(Box::new(expression))
+}
+
+/// Collects the operands of a chain of AND (`conjunctive`) or OR operations.
+fn flatten(expression: &Expression, conjunctive: bool) -> Vec<Expression> {
+    match (expression, conjunctive) {
+        (Expression::And(left, right), true) | (Expression::Or(left, right), false) => {
+            let mut operands = flatten(left, conjunctive);
+            operands.extend(flatten(right, conjunctive));
+            operands
+        }
+        _ => vec![expression.clone()],
+    }
+}
+
+/// Builds a left-associative chain of AND (`conjunctive`) or OR operations.
+fn chain(operands: Vec<Expression>, conjunctive: bool) -> Expression {
+    operands
+        .into_iter()
+        .reduce(|left, right| {
+            if conjunctive {
+                Expression::And(Box::new(left), Box::new(right))
+            } else {
+                Expression::Or(Box::new(left), Box::new(right))
+            }
+        })
+        .unwrap_or(Expression::Constant(conjunctive))
+}
+
+/// Returns the operands without the one at `index`.
+fn without(operands: &[Expression], index: usize) -> Vec<Expression> {
+    operands
+        .iter()
+        .enumerate()
+        .filter(|&(other, _)| other != index)
+        .map(|(_, operand)| operand.clone())
+        .collect()
+}
+
+/// Rebuilds a binary operation with new operands.
+fn with_operands(expression: &Expression, left: Expression, right: Expression) -> Expression {
+    let (left, right) = (Box::new(left), Box::new(right));
+    match expression {
+        Expression::And(..) => Expression::And(left, right),
+        Expression::Nand(..) => Expression::Nand(left, right),
+        Expression::Or(..) => Expression::Or(left, right),
+        Expression::Nor(..) => Expression::Nor(left, right),
+        Expression::Xor(..) => Expression::Xor(left, right),
+        Expression::Xnor(..) => Expression::Xnor(left, right),
+        Expression::Implies(..) => Expression::Implies(left, right),
+        _ => unreachable!(),
+    }
+}
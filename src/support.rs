@@ -0,0 +1,135 @@
+use crate::ast::Expression;
+use crate::cube::{Cube, full_mask};
+use crate::truth_table::{TruthTable, TruthTableRow};
+
+impl TruthTable {
+    /// Checks whether the output of the table depends on a variable.
+    ///
+    /// A variable is in the functional support if flipping it changes the output for at least one row.
+    /// Don't-care rows are compared by their evaluated result, so dropping vacuous variables never changes a care row.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier to check.
+    ///
+    /// # Returns
+    /// `true` if `variable` is a column of the table and the output depends on it.
+    pub fn depends_on(&self, variable: char) -> bool {
+        match self.variables.iter().position(|&v| v == variable) {
+            Some(index) => self.depends_on_index(index),
+            None => false,
+        }
+    }
+
+    /// Computes the functional support of the table.
+    ///
+    /// # Returns
+    /// A sorted `Vec<char>` of variables the output actually depends on.
+    pub fn support(&self) -> Vec<char> {
+        self.variables
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| self.depends_on_index(index))
+            .map(|(_, &variable)| variable)
+            .collect()
+    }
+
+    /// Collects variables that are columns of the table but do not affect the output.
+    ///
+    /// # Returns
+    /// A sorted `Vec<char>` of vacuous variables.
+    pub fn vacuous_variables(&self) -> Vec<char> {
+        self.variables
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| !self.depends_on_index(index))
+            .map(|(_, &variable)| variable)
+            .collect()
+    }
+
+    /// Creates a new table without the columns of vacuous variables.
+    ///
+    /// Rows are kept in the same order, so the first remaining variable is still the least significant one.
+    /// Merged rows are don't-cares only if all of them are.
+    ///
+    /// # Returns
+    /// A [TruthTable] over the functional support only.
+    pub fn without_vacuous_variables(&self) -> TruthTable {
+        let kept: Vec<usize> = (0..self.variables.len())
+            .filter(|&index| self.depends_on_index(index))
+            .collect();
+        let vacuous_mask: usize = (0..self.variables.len())
+            .filter(|index| !kept.contains(index))
+            .map(|index| 1 << index)
+            .sum();
+
+        let variables_length = self.variables.len();
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|&(row_index, _)| row_index & vacuous_mask == 0)
+            .map(|(row_index, row)| {
+                let merged = Cube::new(
+                    row_index as u64,
+                    full_mask(variables_length) & !vacuous_mask as u64,
+                );
+
+                TruthTableRow::new(
+                    kept.iter().map(|&index| row.values[index]).collect(),
+                    row.result,
+                )
+                .with_dont_care(
+                    merged
+                        .minterms(variables_length)
+                        .all(|minterm| self.rows[minterm].dont_care),
+                )
+            })
+            .collect();
+
+        TruthTable {
+            variables: kept.iter().map(|&index| self.variables[index]).collect(),
+            rows,
+        }
+    }
+
+    /// Helper method that checks whether the output depends on the variable in column `index`.
+    ///
+    /// Relies on the row ordering of [TruthTable::from], where bit `index` of the row number is the value of the variable.
+    fn depends_on_index(&self, index: usize) -> bool {
+        let bit = 1 << index;
+        (0..self.rows.len())
+            .filter(|row_index| row_index & bit == 0)
+            .any(|row_index| self.rows[row_index].result != self.rows[row_index | bit].result)
+    }
+}
+
+impl Expression {
+    /// Computes the functional support of the expression.
+    ///
+    /// Unlike [Expression::variables], variables that appear in the expression but cannot change its value are left out.
+    ///
+    /// # Returns
+    /// A sorted `Vec<char>` of variables the expression actually depends on.
+    pub fn support(&self) -> Vec<char> {
+        TruthTable::from(self).support()
+    }
+
+    /// Collects variables that appear in the expression but do not affect its value.
+    ///
+    /// # Returns
+    /// A sorted `Vec<char>` of vacuous variables.
+    pub fn vacuous_variables(&self) -> Vec<char> {
+        TruthTable::from(self).vacuous_variables()
+    }
+
+    /// Checks whether the value of the expression depends on a variable.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier to check.
+    ///
+    /// # Returns
+    /// `true` if some assignment of the other variables makes the two cofactors of `variable` differ.
+    pub fn depends_on(&self, variable: char) -> bool {
+        TruthTable::from(self).depends_on(variable)
+    }
+}
@@ -1,14 +1,20 @@
 use crate::ast::Expression;
+use crate::compiled::{BLOCK_SIZE, Program};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
 /// Represents a single row in the truth table, containing variable values and the evaluated result.
+///
+/// The struct is `#[non_exhaustive]`, so rows are built outside the crate with [TruthTableRow::new].
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct TruthTableRow {
     /// The specific boolean values for each variable in this row
     pub values: Vec<bool>,
     /// The evaluated boolean result of the expression for this row.
     pub result: bool,
+    /// Whether the output of this row does not matter, in which case `result` is ignored by minimization.
+    pub dont_care: bool,
 }
 
 /// Represents the complete truth table for a given expression, with all possible value combinations and their corresponding results.
@@ -20,6 +26,27 @@ pub struct TruthTable {
     pub rows: Vec<TruthTableRow>,
 }
 
+impl TruthTableRow {
+    /// Creates a row whose output matters.
+    ///
+    /// # Arguments
+    /// * `values` - The value of each variable, in the order of [TruthTable::variables].
+    /// * `result` - The output of the row.
+    pub fn new(values: Vec<bool>, result: bool) -> Self {
+        Self {
+            values,
+            result,
+            dont_care: false,
+        }
+    }
+
+    /// Marks whether the output of the row does not matter.
+    pub fn with_dont_care(mut self, dont_care: bool) -> Self {
+        self.dont_care = dont_care;
+        self
+    }
+}
+
 impl Display for TruthTableRow {
     /// Formats a [TruthTableRow] for printing.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -27,7 +54,12 @@ impl Display for TruthTableRow {
         for &value in &self.values {
             write!(f, " {} |", if value { '1' } else { '0' })?;
         }
-        write!(f, "   {}    |", if self.result { '1' } else { '0' })?;
+        let result = match (self.dont_care, self.result) {
+            (true, _) => '-',
+            (false, true) => '1',
+            (false, false) => '0',
+        };
+        write!(f, "   {}    |", result)?;
 
         Ok(())
     }
@@ -55,22 +87,18 @@ impl Display for TruthTable {
         Ok(())
     }
 }
-impl From<&Expression> for TruthTable {
-    /// Creates a `TruthTable`.
+impl TruthTable {
+    /// Creates a `TruthTable` by interpreting the AST for every row.
     ///
-    /// It generates a truth table for a given logical expression showing the evaluation result for all possible combinations of its input variables.
+    /// This is the reference implementation that [TruthTable::from] is checked and benchmarked against.
     ///
     /// # Arguments
     /// * `expression` - An AST node that represents a parsed logical expression.
-    fn from(expression: &Expression) -> Self {
+    pub fn from_interpreted(expression: &Expression) -> Self {
         let variables = expression.variables();
 
         let variables_length = variables.len();
-        let rows_length = if variables_length == 0 {
-            0
-        } else {
-            2_usize.pow(variables_length as u32)
-        };
+        let rows_length = 2_usize.pow(variables_length as u32);
 
         let mut rows: Vec<TruthTableRow> = Vec::with_capacity(rows_length);
         for row_index in 0..rows_length {
@@ -83,12 +111,49 @@ impl From<&Expression> for TruthTable {
                 idens_values.insert(identifier, value);
             }
 
-            rows.push(TruthTableRow {
+            rows.push(TruthTableRow::new(
                 values,
-                result: expression.evaluate(&idens_values),
-            });
+                expression.evaluate(&idens_values),
+            ));
         }
 
         Self { variables, rows }
     }
 }
+
+impl From<&Expression> for TruthTable {
+    /// Creates a `TruthTable`.
+    ///
+    /// It generates a truth table for a given logical expression showing the evaluation result for all possible combinations of its input variables.
+    /// The expression is compiled into a bit-parallel [Program] that evaluates 64 rows at a time.
+    ///
+    /// # Arguments
+    /// * `expression` - An AST node that represents a parsed logical expression.
+    fn from(expression: &Expression) -> Self {
+        let program = Program::compile(expression);
+        let variables_length = program.variables.len();
+        let rows_length = program.rows_length();
+
+        let mut rows: Vec<TruthTableRow> = Vec::with_capacity(rows_length);
+        let mut registers = Vec::with_capacity(program.instructions.len());
+        for block in 0..program.blocks_length() {
+            let results = program.evaluate_block(block, &mut registers);
+
+            for row_index in block * BLOCK_SIZE..rows_length.min((block + 1) * BLOCK_SIZE) {
+                let values = (0..variables_length)
+                    .map(|identifier_index| row_index >> identifier_index & 1 == 1)
+                    .collect();
+
+                rows.push(TruthTableRow::new(
+                    values,
+                    results >> (row_index % BLOCK_SIZE) & 1 == 1,
+                ));
+            }
+        }
+
+        Self {
+            variables: program.variables,
+            rows,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::ast::Expression;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+/// A universal gate that can implement any Boolean function on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum UniversalGate {
+    /// NOT AND: `A NAND A` is `NOT A`, and `(A NAND B) NAND (A NAND B)` is `A AND B`.
+    Nand,
+    /// NOT OR: `A NOR A` is `NOT A`, and `(A NOR B) NOR (A NOR B)` is `A OR B`.
+    Nor,
+}
+
+impl Display for UniversalGate {
+    /// Formats a [UniversalGate] using its keyword.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UniversalGate::Nand => write!(f, "NAND"),
+            UniversalGate::Nor => write!(f, "NOR"),
+        }
+    }
+}
+
+impl Expression {
+    /// Converts the expression into an equivalent one using only NAND operations.
+    pub fn to_nand_only(&self) -> Expression {
+        self.to_universal(UniversalGate::Nand)
+    }
+
+    /// Converts the expression into an equivalent one using only NOR operations.
+    pub fn to_nor_only(&self) -> Expression {
+        self.to_universal(UniversalGate::Nor)
+    }
+
+    /// Converts the expression into an equivalent one using only the given universal gate.
+    ///
+    /// A NOT is implemented by feeding the same signal into both inputs of the gate, and a double inversion
+    /// created by the conversion is removed instead of being built from two gates.
+    ///
+    /// # Returns
+    /// An [Expression] made of identifiers, constants and operations of the given gate.
+    pub fn to_universal(&self, gate: UniversalGate) -> Expression {
+        let converter = Converter { gate };
+        converter.convert(self)
+    }
+
+    /// Counts the gates of the circuit implementing the expression.
+    ///
+    /// Every operation, including NOT, is one gate, and identical subexpressions share a single gate.
+    pub fn gate_count(&self) -> usize {
+        let mut gates = HashSet::new();
+        collect_gates(self, &mut gates);
+        gates.len()
+    }
+}
+
+/// Helper function that collects every distinct operation of an expression.
+fn collect_gates<'a>(expression: &'a Expression, gates: &mut HashSet<&'a Expression>) {
+    match expression {
+        Expression::Constant(_) | Expression::Identifier(_) => {}
+        Expression::Not(expr) => {
+            if gates.insert(expression) {
+                collect_gates(expr, gates);
+            }
+        }
+        Expression::And(left, right)
+        | Expression::Nand(left, right)
+        | Expression::Or(left, right)
+        | Expression::Nor(left, right)
+        | Expression::Xor(left, right)
+        | Expression::Xnor(left, right)
+        | Expression::Implies(left, right) => {
+            if gates.insert(expression) {
+                collect_gates(left, gates);
+                collect_gates(right, gates);
+            }
+        }
+    }
+}
+
+/// Converts expressions into circuits of a single universal gate.
+///
+/// NAND and NOR are duals, so the conversion is written once: for NAND the gate is an inverted AND and
+/// an OR is a gate of inverted inputs, while for NOR the roles of AND and OR are swapped.
+struct Converter {
+    gate: UniversalGate,
+}
+
+impl Converter {
+    /// Recursively converts an expression.
+    fn convert(&self, expression: &Expression) -> Expression {
+        match expression {
+            Expression::Constant(_) | Expression::Identifier(_) => expression.clone(),
+            Expression::Not(expr) => self.not(self.convert(expr)),
+            Expression::And(left, right) => self.and(self.convert(left), self.convert(right)),
+            Expression::Nand(left, right) => {
+                self.not(self.and(self.convert(left), self.convert(right)))
+            }
+            Expression::Or(left, right) => self.or(self.convert(left), self.convert(right)),
+            Expression::Nor(left, right) => {
+                self.not(self.or(self.convert(left), self.convert(right)))
+            }
+            Expression::Xor(left, right) => self.xor(self.convert(left), self.convert(right)),
+            Expression::Xnor(left, right) => {
+                self.not(self.xor(self.convert(left), self.convert(right)))
+            }
+            Expression::Implies(left, right) => {
+                self.or(self.not(self.convert(left)), self.convert(right))
+            }
+        }
+    }
+
+    /// Builds a single gate.
+    fn gate(&self, left: Expression, right: Expression) -> Expression {
+        match self.gate {
+            UniversalGate::Nand => Expression::Nand(Box::new(left), Box::new(right)),
+            UniversalGate::Nor => Expression::Nor(Box::new(left), Box::new(right)),
+        }
+    }
+
+    /// Inverts a signal with a gate whose inputs are tied together, or removes an existing inversion.
+    fn not(&self, expression: Expression) -> Expression {
+        match (&expression, self.gate) {
+            (Expression::Nand(left, right), UniversalGate::Nand)
+            | (Expression::Nor(left, right), UniversalGate::Nor)
+                if left == right =>
+            {
+                (**left).clone()
+            }
+            (Expression::Constant(value), _) => Expression::Constant(!value),
+            _ => self.gate(expression.clone(), expression),
+        }
+    }
+
+    /// Builds an AND: an inverted NAND, or a NOR of inverted inputs.
+    fn and(&self, left: Expression, right: Expression) -> Expression {
+        match self.gate {
+            UniversalGate::Nand => self.not(self.gate(left, right)),
+            UniversalGate::Nor => self.gate(self.not(left), self.not(right)),
+        }
+    }
+
+    /// Builds an OR: a NAND of inverted inputs, or an inverted NOR.
+    fn or(&self, left: Expression, right: Expression) -> Expression {
+        match self.gate {
+            UniversalGate::Nand => self.gate(self.not(left), self.not(right)),
+            UniversalGate::Nor => self.not(self.gate(left, right)),
+        }
+    }
+
+    /// Builds a XOR from the classic four-gate circuit.
+    ///
+    /// With NAND gates the circuit computes XOR directly, with NOR gates it computes XNOR and is inverted.
+    fn xor(&self, left: Expression, right: Expression) -> Expression {
+        let shared = self.gate(left.clone(), right.clone());
+        let circuit = self.gate(self.gate(left, shared.clone()), self.gate(right, shared));
+
+        match self.gate {
+            UniversalGate::Nand => circuit,
+            UniversalGate::Nor => self.not(circuit),
+        }
+    }
+}
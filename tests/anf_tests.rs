@@ -0,0 +1,66 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+mod common;
+use common::results_over;
+
+#[test]
+fn test_anf() -> Result<()> {
+    let expression: Expression = "A OR B".parse()?;
+    let anf = expression.to_anf();
+    assert_eq!(anf.variables, vec!['A', 'B']);
+    assert_eq!(anf.monomials, vec![0b01, 0b10, 0b11]);
+    assert_eq!(anf.degree(), 2);
+    assert_eq!(anf.to_string(), "A XOR B XOR (A AND B)");
+
+    let expression: Expression = "A XNOR B".parse()?;
+    let anf = expression.to_anf();
+    assert_eq!(anf.to_string(), "1 XOR A XOR B");
+    assert_eq!(anf.degree(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_anf_monomial_order() -> Result<()> {
+    let expression: Expression = "(A AND D) XOR (B AND C)".parse()?;
+    let anf = expression.to_anf();
+    assert_eq!(anf.to_string(), "(A AND D) XOR (B AND C)");
+
+    Ok(())
+}
+
+#[test]
+fn test_anf_constant_functions() -> Result<()> {
+    let expression: Expression = "A AND NOT A".parse()?;
+    let anf = expression.to_anf();
+    assert!(anf.monomials.is_empty());
+    assert_eq!(anf.degree(), 0);
+    assert_eq!(anf.to_string(), "0");
+
+    let expression: Expression = "A OR NOT A".parse()?;
+    let anf = expression.to_anf();
+    assert_eq!(anf.monomials, vec![0]);
+    assert_eq!(anf.to_string(), "1");
+
+    Ok(())
+}
+
+#[test]
+fn test_anf_is_equivalent() -> Result<()> {
+    for input in [
+        "NOT (A AND B) OR A",
+        "!(A NAND B) NOR !C",
+        "!(A XOR B) AND (C XNOR !D)",
+        "A AND B OR C AND D OR A AND D",
+    ] {
+        let expression: Expression = input.parse()?;
+        let anf = expression.to_anf();
+
+        assert_eq!(
+            results_over(&anf.to_expression(), &anf.variables),
+            results_over(&expression, &anf.variables)
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use logical_expression_pest_parser::assignment::{
+    BitmaskAssignment, EvaluationError, SliceAssignment,
+};
+use logical_expression_pest_parser::ast::Expression;
+use std::collections::HashMap;
+
+#[test]
+fn test_try_evaluate_reports_missing_variables() -> Result<()> {
+    let expression: Expression = "A AND (B OR C) XOR D".parse()?;
+    let result = expression.try_evaluate(&HashMap::from([('A', true), ('C', false)]));
+
+    assert_eq!(
+        result,
+        Err(EvaluationError::MissingVariables(vec!['B', 'D']))
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Missing values for variables: B, D"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_lenient_evaluation_defaults_to_false() -> Result<()> {
+    let expression: Expression = "A NOR B".parse()?;
+    let variables = HashMap::from([('A', false)]);
+
+    assert!(expression.evaluate_lenient(&variables));
+    assert!(expression.evaluate(&variables));
+
+    Ok(())
+}
+
+#[test]
+fn test_assignment_backends_agree() -> Result<()> {
+    let expression: Expression = "(A NAND B) XNOR C".parse()?;
+    let variables = expression.variables();
+
+    for mask in 0..8u64 {
+        let values: Vec<bool> = (0..3).map(|index| mask >> index & 1 == 1).collect();
+        let map: HashMap<char, bool> = variables.iter().copied().zip(values.clone()).collect();
+
+        let expected = expression.try_evaluate(&map)?;
+        assert_eq!(
+            expression.try_evaluate(&SliceAssignment::new(&variables, &values))?,
+            expected
+        );
+        assert_eq!(
+            expression.try_evaluate(&BitmaskAssignment::new(&variables, mask))?,
+            expected
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::truth_table::TruthTable;
+mod common;
+use common::results_over;
+
+#[test]
+fn test_minterm_and_maxterm_notation() -> Result<()> {
+    let truth_table = TruthTable::from(&"(A AND B) OR C".parse()?);
+
+    assert_eq!(truth_table.minterms(), vec![3, 4, 5, 6, 7]);
+    assert_eq!(truth_table.maxterms(), vec![0, 1, 2]);
+    assert_eq!(truth_table.minterm_notation(), "Σm(3,4,5,6,7)");
+    assert_eq!(truth_table.maxterm_notation(), "ΠM(0,1,2)");
+
+    Ok(())
+}
+
+#[test]
+fn test_first_variable_is_least_significant() -> Result<()> {
+    let truth_table = TruthTable::from(&"A AND NOT B".parse()?);
+
+    assert_eq!(truth_table.minterm_notation(), "Σm(1)");
+    assert_eq!(truth_table.to_sum_of_minterms().to_string(), "A AND NOT B");
+    assert_eq!(
+        truth_table.to_product_of_maxterms().to_string(),
+        "(A OR B) AND (A OR NOT B) AND (NOT A OR NOT B)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_canonical_forms_are_equivalent() -> Result<()> {
+    for input in ["A XOR B XOR C", "(A NAND B) NOR !C", "A OR !A", "A AND !A"] {
+        let expression: Expression = input.parse()?;
+        let variables = expression.variables();
+        let truth_table = TruthTable::from(&expression);
+        let expected = results_over(&expression, &variables);
+
+        assert_eq!(
+            results_over(&truth_table.to_sum_of_minterms(), &variables),
+            expected
+        );
+        assert_eq!(
+            results_over(&truth_table.to_product_of_maxterms(), &variables),
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_canonical_forms_of_constants() -> Result<()> {
+    let truth_table = TruthTable::from(&"A OR !A".parse()?);
+    assert_eq!(truth_table.to_sum_of_minterms().to_string(), "NOT A OR A");
+    assert_eq!(
+        truth_table.to_product_of_maxterms(),
+        Expression::Constant(true)
+    );
+
+    let truth_table = TruthTable::from(&"A AND !A".parse()?);
+    assert_eq!(
+        truth_table.to_sum_of_minterms(),
+        Expression::Constant(false)
+    );
+    assert_eq!(
+        truth_table.to_product_of_maxterms().to_string(),
+        "A AND NOT A"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::classification::Classification;
+use logical_expression_pest_parser::truth_table::TruthTable;
+
+#[test]
+fn test_classify_expression() -> Result<()> {
+    let tautology: Expression = "A OR NOT A".parse()?;
+    assert_eq!(tautology.classify(), Classification::Tautology);
+    assert_eq!(tautology.satisfying_count(), 2);
+    assert!(tautology.is_tautology());
+
+    let contradiction: Expression = "A AND NOT A AND B".parse()?;
+    assert_eq!(contradiction.classify(), Classification::Contradiction);
+    assert_eq!(contradiction.satisfying_count(), 0);
+    assert!(contradiction.is_contradiction());
+    assert!(!contradiction.is_satisfiable());
+
+    let contingent: Expression = "A AND B OR C".parse()?;
+    assert_eq!(contingent.classify(), Classification::Contingent);
+    assert_eq!(contingent.satisfying_count(), 5);
+    assert!(contingent.is_satisfiable());
+    assert_eq!(contingent.classify().to_string(), "contingent");
+
+    Ok(())
+}
+
+#[test]
+fn test_satisfying_count_many_variables() -> Result<()> {
+    let expression: Expression = "A AND B AND C AND D AND E AND F AND G OR H".parse()?;
+    assert_eq!(expression.satisfying_count(), 129);
+    assert_eq!(
+        expression.satisfying_count(),
+        TruthTable::from(&expression).satisfying_count()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_classify_truth_table_with_dont_cares() -> Result<()> {
+    let expression: Expression = "A AND B".parse()?;
+    let truth_table = TruthTable::from(&expression).with_dont_cares(&[0, 1, 2])?;
+
+    assert_eq!(truth_table.care_count(), 1);
+    assert_eq!(truth_table.satisfying_count(), 1);
+    assert_eq!(truth_table.classify(), Classification::Tautology);
+
+    Ok(())
+}
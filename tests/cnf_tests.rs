@@ -0,0 +1,108 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::literal::Variable;
+use std::collections::HashMap;
+
+const INPUTS: [&str; 5] = [
+    "NOT (A AND B) OR C",
+    "(A XOR B) NAND (C NOR !A)",
+    "A XNOR B XNOR C",
+    "(A OR B) AND (A OR !B) AND !(C AND !C)",
+    "NOT (A -> B) OR (B -> C)",
+];
+
+fn input_assignment(variables: &[char], row: usize) -> HashMap<char, bool> {
+    variables
+        .iter()
+        .enumerate()
+        .map(|(index, &variable)| (variable, row >> index & 1 == 1))
+        .collect()
+}
+
+#[test]
+fn test_distributive_cnf_is_equivalent() -> Result<()> {
+    for input in INPUTS {
+        let expression: Expression = input.parse()?;
+        let cnf = expression.to_cnf();
+        let variables = expression.variables();
+
+        assert!(
+            cnf.variables()
+                .iter()
+                .all(|variable| matches!(variable, Variable::Input(_)))
+        );
+
+        for row in 0..1 << variables.len() {
+            let assignment = input_assignment(&variables, row);
+            let cnf_assignment = assignment
+                .iter()
+                .map(|(&variable, &value)| (Variable::Input(variable), value))
+                .collect();
+
+            assert_eq!(
+                cnf.evaluate(&cnf_assignment),
+                expression.evaluate(&assignment)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_distributive_cnf_removes_redundant_clauses() -> Result<()> {
+    let expression: Expression = "(A OR B) AND (A OR (A AND C)) AND (B OR !B)".parse()?;
+    assert_eq!(expression.to_cnf().to_string(), "(A)");
+
+    Ok(())
+}
+
+#[test]
+fn test_tseitin_cnf_is_equisatisfiable() -> Result<()> {
+    for input in INPUTS {
+        let expression: Expression = input.parse()?;
+        let cnf = expression.to_tseitin_cnf();
+        let variables = expression.variables();
+        let auxiliaries: Vec<Variable> = cnf
+            .variables()
+            .into_iter()
+            .filter(|variable| matches!(variable, Variable::Auxiliary(_)))
+            .collect();
+
+        for row in 0..1 << variables.len() {
+            let assignment = input_assignment(&variables, row);
+            let satisfying_extensions = (0..1 << auxiliaries.len())
+                .filter(|auxiliary_row| {
+                    let mut cnf_assignment: HashMap<Variable, bool> = assignment
+                        .iter()
+                        .map(|(&variable, &value)| (Variable::Input(variable), value))
+                        .collect();
+                    for (index, &auxiliary) in auxiliaries.iter().enumerate() {
+                        cnf_assignment.insert(auxiliary, auxiliary_row >> index & 1 == 1);
+                    }
+                    cnf.evaluate(&cnf_assignment)
+                })
+                .count();
+
+            let expected = if expression.evaluate(&assignment) {
+                1
+            } else {
+                0
+            };
+            assert_eq!(satisfying_extensions, expected);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_dimacs_output() -> Result<()> {
+    let expression: Expression = "(A OR !B) AND B".parse()?;
+    assert_eq!(
+        expression.to_cnf().to_dimacs(),
+        "c 1 A\nc 2 B\np cnf 2 2\n2 0\n1 -2 0\n"
+    );
+
+    Ok(())
+}
@@ -1,5 +1,11 @@
+//! Helpers shared by the integration tests; each test file uses only some of them.
+#![allow(dead_code)]
+
+use logical_expression_pest_parser::ast::Expression;
 use logical_expression_pest_parser::parser::{Grammar, Rule};
+use logical_expression_pest_parser::truth_table::TruthTable;
 use pest::Parser;
+use std::collections::HashMap;
 
 pub fn test_if_valid(rule: Rule, data: &[&str]) -> anyhow::Result<()> {
     for some in data {
@@ -19,3 +25,26 @@ pub fn test_if_invalid(rule: Rule, data: &[&str]) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Collects the results of all rows of the truth table of an expression.
+pub fn results(expression: &Expression) -> Vec<bool> {
+    TruthTable::from(expression)
+        .rows
+        .iter()
+        .map(|row| row.result)
+        .collect()
+}
+
+/// Collects the results of an expression for all assignments of the given variables, the first one being the lowest bit.
+pub fn results_over(expression: &Expression, variables: &[char]) -> Vec<bool> {
+    (0..1usize << variables.len())
+        .map(|row| {
+            let values: HashMap<char, bool> = variables
+                .iter()
+                .enumerate()
+                .map(|(index, &variable)| (variable, row >> index & 1 == 1))
+                .collect();
+            expression.evaluate(&values)
+        })
+        .collect()
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::compiled::Program;
+use logical_expression_pest_parser::truth_table::TruthTable;
+
+fn assert_same_table(input: &str) -> Result<()> {
+    let expression: Expression = input.parse()?;
+    let compiled = TruthTable::from(&expression);
+    let interpreted = TruthTable::from_interpreted(&expression);
+
+    assert_eq!(compiled.variables, interpreted.variables);
+    assert_eq!(compiled.rows.len(), interpreted.rows.len());
+    for (compiled_row, interpreted_row) in compiled.rows.iter().zip(&interpreted.rows) {
+        assert_eq!(compiled_row.values, interpreted_row.values);
+        assert_eq!(compiled_row.result, interpreted_row.result);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_compiled_matches_interpreted_small() -> Result<()> {
+    assert_same_table("A")?;
+    assert_same_table("!A NAND B")?;
+    assert_same_table("(A NOR B) XNOR !(C XOR A) OR D AND !E")?;
+    assert_same_table("A -> !B -> C AND (D -> A)")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_compiled_matches_interpreted_multiple_blocks() -> Result<()> {
+    assert_same_table("(A AND B) XOR (C OR D) NAND (E XNOR F) NOR (G AND !H) OR I")
+}
+
+#[test]
+fn test_common_subexpressions_are_shared() -> Result<()> {
+    let expression: Expression = "(A AND B) OR (A AND B) XOR C".parse()?;
+    let program = Program::compile(&expression);
+
+    assert_eq!(program.instructions.len(), 6);
+    assert!(program.evaluate_row(0b011));
+    assert!(program.evaluate_row(0b100));
+    assert!(!program.evaluate_row(0b000));
+
+    Ok(())
+}
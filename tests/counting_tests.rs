@@ -0,0 +1,96 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::cnf::{Clause, Cnf};
+use logical_expression_pest_parser::literal::{Literal, Variable};
+use num_bigint::BigUint;
+
+/// Combines expressions with a binary operator.
+fn chain(
+    expressions: impl IntoIterator<Item = Expression>,
+    operator: fn(Box<Expression>, Box<Expression>) -> Expression,
+) -> Expression {
+    expressions
+        .into_iter()
+        .reduce(|left, right| operator(Box::new(left), Box::new(right)))
+        .unwrap()
+}
+
+#[test]
+fn test_count_matches_truth_table() -> Result<()> {
+    for input in [
+        "A",
+        "A AND NOT A",
+        "A OR NOT A",
+        "A OR B AND C",
+        "A XOR B XOR C XOR D",
+        "(A NAND B) XNOR (C NOR D)",
+        "(A OR B) AND (NOT A OR C) AND (B XOR D) AND (E OR NOT C)",
+        "(A AND B) OR (C AND D) OR (E AND F) OR (G AND H)",
+    ] {
+        let expression: Expression = input.parse()?;
+        assert_eq!(
+            expression.count_models(),
+            BigUint::from(expression.satisfying_count()),
+            "{}",
+            input
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_count_with_many_variables() {
+    let upper = ('A'..='Z').map(Expression::Identifier);
+    let lower = ('a'..='z').map(Expression::Identifier);
+
+    let pairs = chain(
+        upper
+            .clone()
+            .zip(lower.clone())
+            .map(|(left, right)| Expression::Or(Box::new(left), Box::new(right))),
+        Expression::And,
+    );
+    assert_eq!(pairs.variables().len(), 52);
+    assert_eq!(pairs.count_models(), BigUint::from(3u8).pow(26));
+
+    let parity = chain(upper.chain(lower), Expression::Xor);
+    assert_eq!(parity.count_models(), BigUint::from(1u8) << 51);
+}
+
+#[test]
+fn test_count_with_constants() {
+    let expression = Expression::And(
+        Box::new(Expression::Identifier('A')),
+        Box::new(Expression::Constant(false)),
+    );
+    assert_eq!(expression.count_models(), BigUint::ZERO);
+
+    let expression = Expression::Or(
+        Box::new(Expression::Identifier('A')),
+        Box::new(Expression::Constant(true)),
+    );
+    assert_eq!(expression.count_models(), BigUint::from(2u8));
+}
+
+#[test]
+fn test_count_cnf() {
+    let a = Variable::Input('A');
+    let b = Variable::Input('B');
+    let c = Variable::Input('C');
+    let cnf = Cnf {
+        clauses: vec![
+            Clause::new([Literal::positive(a), Literal::positive(b)]),
+            Clause::new([Literal::negative(b), Literal::positive(c)]),
+        ],
+    };
+    assert_eq!(cnf.count_models(), BigUint::from(4u8));
+
+    let empty = Cnf { clauses: vec![] };
+    assert_eq!(empty.count_models(), BigUint::from(1u8));
+
+    let contradiction = Cnf {
+        clauses: vec![Clause::new([])],
+    };
+    assert_eq!(contradiction.count_models(), BigUint::ZERO);
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+mod common;
+use common::results;
+
+#[test]
+fn test_dnf_is_equivalent() -> Result<()> {
+    for input in [
+        "NOT (A AND B) OR C",
+        "(A XOR B) NAND (C NOR !A)",
+        "(A OR B) AND (C OR D)",
+        "A XNOR B XNOR C",
+    ] {
+        let expression: Expression = input.parse()?;
+        let dnf = expression.to_dnf();
+        let reparsed: Expression = dnf.to_string().parse()?;
+
+        assert_eq!(results(&dnf.to_expression()), results(&expression));
+        assert_eq!(results(&reparsed), results(&expression));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_dnf_removes_subsumed_terms() -> Result<()> {
+    let expression: Expression = "A OR (A AND B) OR (B AND !B) OR (A AND C)".parse()?;
+    assert_eq!(expression.to_dnf().to_string(), "A");
+
+    Ok(())
+}
+
+#[test]
+fn test_dnf_distributes() -> Result<()> {
+    let expression: Expression = "(A OR B) AND C".parse()?;
+    assert_eq!(expression.to_dnf().to_string(), "A AND C OR B AND C");
+
+    Ok(())
+}
+
+#[test]
+fn test_dnf_constants() -> Result<()> {
+    assert_eq!("A AND !A".parse::<Expression>()?.to_dnf().to_string(), "0");
+    assert_eq!(Expression::Constant(true).to_dnf().to_string(), "1");
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::dont_care::DontCareError;
+use logical_expression_pest_parser::kmap::KarnaughMap;
+use logical_expression_pest_parser::parser::{Rule, parse};
+use logical_expression_pest_parser::truth_table::TruthTable;
+
+#[test]
+fn test_dont_care_specification() -> Result<()> {
+    let input = "A AND B OR C\ndontcare A AND NOT B\nNOT A\n";
+    let file_pair = parse(input)?.next().unwrap();
+    let specifications: Vec<_> = file_pair
+        .into_inner()
+        .filter(|pair| pair.as_rule() == Rule::specification)
+        .collect();
+    assert_eq!(specifications.len(), 2);
+
+    let inner: Vec<_> = specifications[0].clone().into_inner().collect();
+    assert_eq!(inner.len(), 2);
+    assert_eq!(inner[1].as_rule(), Rule::dontcare);
+    assert_eq!(
+        Expression::ast(inner[1].clone()),
+        "A AND NOT B".parse::<Expression>()?
+    );
+    assert_eq!(
+        Expression::ast(specifications[0].clone()),
+        "A AND B OR C".parse::<Expression>()?
+    );
+
+    assert!(parse("dontcare A\n").is_err());
+    assert!(parse("A\ndontcare\n").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_with_dont_care_condition() -> Result<()> {
+    let expression: Expression = "A AND B".parse()?;
+    let dont_care: Expression = "A AND C".parse()?;
+    let truth_table = TruthTable::with_dont_care_condition(&expression, &dont_care);
+
+    assert_eq!(truth_table.variables, vec!['A', 'B', 'C']);
+    assert_eq!(truth_table.dont_cares(), vec![5, 7]);
+    assert_eq!(truth_table.minterms(), vec![3]);
+    assert_eq!(truth_table.maxterms(), vec![0, 1, 2, 4, 6]);
+    assert_eq!(truth_table.minterm_notation(), "Σm(3) + d(5,7)");
+    assert_eq!(truth_table.maxterm_notation(), "ΠM(0,1,2,4,6) · D(5,7)");
+    assert_eq!(truth_table.rows[5].to_string(), "| 1 | 0 | 1 |   -    |");
+
+    Ok(())
+}
+
+#[test]
+fn test_with_dont_cares() -> Result<()> {
+    let expression: Expression = "A AND B".parse()?;
+    let truth_table = TruthTable::from(&expression).with_dont_cares(&[1, 2])?;
+    assert_eq!(truth_table.dont_cares(), vec![1, 2]);
+    assert_eq!(truth_table.minterm_notation(), "Σm(3) + d(1,2)");
+
+    let error = TruthTable::from(&expression)
+        .with_dont_cares(&[4])
+        .unwrap_err();
+    assert_eq!(
+        error,
+        DontCareError::MintermOutOfRange {
+            minterm: 4,
+            rows_length: 4
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_minimize_with_dont_cares() -> Result<()> {
+    let expression: Expression = "A AND B OR C".parse()?;
+    let dont_care: Expression = "A AND NOT B".parse()?;
+
+    assert_eq!(expression.minimize().to_dnf().to_string(), "A AND B OR C");
+    assert_eq!(
+        expression
+            .minimize_with_dont_cares(&dont_care)
+            .to_dnf()
+            .to_string(),
+        "A OR C"
+    );
+
+    let truth_table = TruthTable::with_dont_care_condition(&expression, &dont_care);
+    assert_eq!(
+        truth_table.minimize_heuristic().to_dnf().to_string(),
+        "A OR C"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_dont_care_support() -> Result<()> {
+    let expression: Expression = "A AND B OR A AND NOT B".parse()?;
+    let truth_table = TruthTable::from(&expression).with_dont_cares(&[1, 2])?;
+    assert_eq!(truth_table.vacuous_variables(), vec!['B']);
+
+    let reduced = truth_table.without_vacuous_variables();
+    assert_eq!(reduced.variables, vec!['A']);
+    assert_eq!(reduced.dont_cares(), vec![] as Vec<usize>);
+    assert_eq!(reduced.minterms(), vec![1]);
+
+    let truth_table = TruthTable::from(&expression).with_dont_cares(&[1, 3])?;
+    assert_eq!(
+        truth_table.without_vacuous_variables().dont_cares(),
+        vec![1]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_kmap_dont_cares() -> Result<()> {
+    let expression: Expression = "A AND B".parse()?;
+    let truth_table = TruthTable::from(&expression).with_dont_cares(&[1])?;
+    let map = KarnaughMap::new(&truth_table)?;
+
+    assert_eq!(map.dont_cares, vec![vec![false, false], vec![true, false]]);
+    assert!(map.to_string().contains("Xa"));
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::entailment::Argument;
+use logical_expression_pest_parser::parser::{Rule, parse_arguments};
+use logical_expression_pest_parser::truth_table::TruthTable;
+
+/// Parses all arguments of an input.
+fn arguments(input: &str) -> Result<Vec<Argument>> {
+    let file_pair = parse_arguments(input)?.next().unwrap();
+
+    Ok(file_pair
+        .into_inner()
+        .filter(|pair| pair.as_rule() == Rule::argument)
+        .map(Argument::ast)
+        .collect())
+}
+
+#[test]
+fn test_implication_parsing() -> Result<()> {
+    for (input, expected) in [
+        ("A -> B", "A -> B"),
+        ("A => B", "A -> B"),
+        ("NOT A -> B", "NOT A -> B"),
+        ("NOT NOT A -> B", "A -> B"),
+        ("A OR B -> C", "A OR B -> C"),
+        ("A -> B -> C", "A -> B -> C"),
+        ("(A -> B) -> C", "(A -> B) -> C"),
+        ("(A -> B) AND A", "(A -> B) AND A"),
+        ("NOT (A -> B)", "NOT (A -> B)"),
+    ] {
+        let expression: Expression = input.parse()?;
+        assert_eq!(expression.to_string(), expected, "{}", input);
+        assert_eq!(expression.to_string().parse::<Expression>()?, expression);
+    }
+
+    let expression: Expression = "A -> B -> C".parse()?;
+    assert_eq!(
+        expression,
+        Expression::Implies(
+            Box::new(Expression::Identifier('A')),
+            Box::new(Expression::Implies(
+                Box::new(Expression::Identifier('B')),
+                Box::new(Expression::Identifier('C'))
+            ))
+        )
+    );
+
+    let results: Vec<bool> = TruthTable::from(&"A -> B".parse::<Expression>()?)
+        .rows
+        .iter()
+        .map(|row| row.result)
+        .collect();
+    assert_eq!(results, vec![true, false, true, true]);
+
+    assert!("A ->".parse::<Expression>().is_err());
+    assert!("-> B".parse::<Expression>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_entails() -> Result<()> {
+    let premises: Vec<Expression> = vec!["A -> B".parse()?, "B -> C".parse()?];
+    assert!(Expression::entails(&premises, &"A -> C".parse()?));
+    assert!(!Expression::entails(&premises, &"C -> A".parse()?));
+
+    assert!(Expression::entails(&[], &"A OR NOT A".parse()?));
+    assert!(!Expression::entails(&[], &"A".parse()?));
+    assert!(Expression::entails(
+        &["A AND NOT A".parse()?],
+        &"B".parse()?
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_arguments_file() -> Result<()> {
+    let arguments = arguments(
+        "premises: A -> B, A\nconclude: B\n\npremises: A -> B, B\nconclude: A\npremises:\nconclude: A -> A\n",
+    )?;
+    assert_eq!(arguments.len(), 3);
+
+    assert_eq!(
+        arguments[0],
+        Argument {
+            premises: vec!["A -> B".parse()?, "A".parse()?],
+            conclusion: "B".parse()?,
+        }
+    );
+    assert!(arguments[0].is_valid());
+    assert_eq!(arguments[0].counterexample(), None);
+
+    assert!(!arguments[1].is_valid());
+    let counterexample = arguments[1].counterexample().unwrap();
+    assert_eq!(counterexample.to_string(), "A = 0, B = 1");
+    for premise in &arguments[1].premises {
+        assert!(premise.evaluate_lenient(&counterexample));
+    }
+    assert!(!arguments[1].conclusion.evaluate_lenient(&counterexample));
+
+    assert!(arguments[2].premises.is_empty());
+    assert!(arguments[2].is_valid());
+
+    Ok(())
+}
+
+#[test]
+fn test_argument_sources() -> Result<()> {
+    let file_pair = parse_arguments("premises: A => B,  NOT NOT A\nconclude: B\n")?
+        .next()
+        .unwrap();
+    let pair = file_pair
+        .into_inner()
+        .find(|pair| pair.as_rule() == Rule::argument)
+        .unwrap();
+
+    assert_eq!(Argument::sources(&pair), (vec!["A => B", "NOT NOT A"], "B"));
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_arguments_file() {
+    for input in [
+        "",
+        "premises: A\n",
+        "conclude: A\n",
+        "premises: A,\nconclude: B\n",
+        "premises: A\nconclude: B, C\n",
+    ] {
+        assert!(parse_arguments(input).is_err(), "{:?}", input);
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::truth_table::TruthTable;
+use std::collections::BTreeSet;
+
+/// Collects the rows of a truth table with a given result as value vectors.
+fn rows_with_result(expression: &Expression, result: bool) -> BTreeSet<Vec<bool>> {
+    TruthTable::from(expression)
+        .rows
+        .into_iter()
+        .filter(|row| row.result == result)
+        .map(|row| row.values)
+        .collect()
+}
+
+#[test]
+fn test_models_match_truth_table() -> Result<()> {
+    for input in [
+        "A OR B AND C",
+        "A XOR B XOR C",
+        "(A NAND B) XNOR (C NOR D)",
+        "A AND NOT A",
+        "A OR NOT A",
+    ] {
+        let expression: Expression = input.parse()?;
+
+        let models: Vec<Vec<bool>> = expression.models().map(|model| model.values).collect();
+        let unique: BTreeSet<Vec<bool>> = models.iter().cloned().collect();
+        assert_eq!(models.len(), unique.len(), "{}", input);
+        assert_eq!(unique, rows_with_result(&expression, true), "{}", input);
+
+        let countermodels: BTreeSet<Vec<bool>> = expression
+            .countermodels()
+            .map(|model| model.values)
+            .collect();
+        assert_eq!(
+            countermodels,
+            rows_with_result(&expression, false),
+            "{}",
+            input
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_projected_models() -> Result<()> {
+    let expression: Expression = "A OR B AND C".parse()?;
+
+    let models: Vec<String> = expression
+        .projected_models(&['B', 'A', 'B'])
+        .map(|model| model.to_string())
+        .collect();
+    let unique: BTreeSet<&String> = models.iter().collect();
+    assert_eq!(unique.len(), 3);
+    assert!(!models.contains(&"A = 0, B = 0".to_string()));
+
+    let models: Vec<String> = expression
+        .projected_models(&['A', 'D'])
+        .map(|model| model.to_string())
+        .collect();
+    assert_eq!(models.len(), 4);
+
+    let models: Vec<String> = expression
+        .projected_models(&[])
+        .map(|model| model.to_string())
+        .collect();
+    assert_eq!(models, vec!["no variables"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_models_with_assumptions() -> Result<()> {
+    let expression: Expression = "A OR B AND C".parse()?;
+
+    let models: Vec<String> = expression
+        .models()
+        .with_assumptions(&[('A', false)])
+        .map(|model| model.to_string())
+        .collect();
+    assert_eq!(models, vec!["A = 0, B = 1, C = 1"]);
+
+    assert_eq!(
+        expression
+            .models()
+            .with_assumptions(&[('A', false), ('B', false)])
+            .count(),
+        0
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_models_are_lazy() -> Result<()> {
+    let variables: Vec<String> = ('A'..='Z').map(|variable| variable.to_string()).collect();
+    let expression: Expression = variables.join(" XOR ").parse()?;
+
+    let models: Vec<_> = expression.models().take(10).collect();
+    assert_eq!(models.len(), 10);
+    for model in &models {
+        assert!(expression.evaluate_lenient(model));
+    }
+
+    let unique: BTreeSet<&Vec<bool>> = models.iter().map(|model| &model.values).collect();
+    assert_eq!(unique.len(), 10);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::equivalence::Counterexample;
+
+#[test]
+fn test_equivalent_expressions() -> Result<()> {
+    for (left, right) in [
+        ("NOT (A AND B)", "NOT A OR NOT B"),
+        ("A NAND B", "!A | !B"),
+        ("A XOR B", "A AND NOT B OR NOT A AND B"),
+        ("A", "A AND (A OR D)"),
+        ("A OR NOT A", "B OR NOT B"),
+    ] {
+        let left: Expression = left.parse()?;
+        let right: Expression = right.parse()?;
+
+        assert!(left.equivalent_to(&right), "{} and {}", left, right);
+        assert_eq!(left.counterexample(&right), None);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_minimal_counterexample() -> Result<()> {
+    let left: Expression = "A OR B AND C".parse()?;
+    let right: Expression = "(A OR B) AND C".parse()?;
+    assert!(!left.equivalent_to(&right));
+
+    let counterexample = left.counterexample(&right).unwrap();
+    assert_eq!(
+        counterexample,
+        Counterexample {
+            variables: vec!['A', 'B', 'C'],
+            values: vec![true, false, false],
+            left: true,
+            right: false,
+        }
+    );
+    assert_eq!(counterexample.to_string(), "A = 1, B = 0, C = 0");
+
+    Ok(())
+}
+
+#[test]
+fn test_counterexample_over_union_of_variables() -> Result<()> {
+    let left: Expression = "A AND B AND C".parse()?;
+    let right: Expression = "D AND E".parse()?;
+
+    let counterexample = left.counterexample(&right).unwrap();
+    assert_eq!(counterexample.variables, vec!['A', 'B', 'C', 'D', 'E']);
+    assert_eq!(
+        counterexample.to_string(),
+        "A = 0, B = 0, C = 0, D = 1, E = 1"
+    );
+    assert!(!counterexample.left);
+    assert!(counterexample.right);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::cube::Cube;
+use logical_expression_pest_parser::espresso::minimize;
+use logical_expression_pest_parser::truth_table::TruthTable;
+
+fn assert_equivalent(left: &Expression, right: &Expression, variables: &[char]) {
+    for row in 0..1usize << variables.len() {
+        let assignment = variables
+            .iter()
+            .enumerate()
+            .map(|(index, &variable)| (variable, row >> index & 1 == 1))
+            .collect();
+        assert_eq!(left.evaluate(&assignment), right.evaluate(&assignment));
+    }
+}
+
+#[test]
+fn test_heuristic_matches_exact_on_small_functions() -> Result<()> {
+    for input in [
+        "A AND B OR NOT A AND C OR B AND C",
+        "(A XOR B) OR (C AND !D) OR (A AND B AND D)",
+        "!(A NAND B) NOR (C XNOR D)",
+    ] {
+        let expression: Expression = input.parse()?;
+        let exact = expression.minimize();
+        let heuristic = expression.minimize_heuristic();
+
+        assert_equivalent(
+            &heuristic.to_expression(),
+            &expression,
+            &expression.variables(),
+        );
+        assert_eq!(heuristic.cubes.len(), exact.cover.len());
+        assert_eq!(heuristic.literal_count(), exact.literal_count());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_heuristic_from_truth_table() -> Result<()> {
+    let expression: Expression = "(A AND B) OR (A AND !B) OR (C AND A)".parse()?;
+    let cover = TruthTable::from(&expression).minimize_heuristic();
+
+    assert_eq!(cover.to_dnf().to_string(), "A");
+
+    Ok(())
+}
+
+#[test]
+fn test_heuristic_uses_dont_cares() -> Result<()> {
+    let expression: Expression = "A AND NOT B".parse()?;
+    let dont_care: Expression = "A AND B".parse()?;
+
+    let cover = expression.minimize_heuristic_with_dont_cares(&dont_care);
+    assert_eq!(cover.to_dnf().to_string(), "A");
+
+    let cover = minimize(&['A', 'B'], &[Cube::minterm(1, 2)], &[Cube::minterm(3, 2)]);
+    assert_eq!(cover.cubes, vec![Cube::new(0b01, 0b01)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_heuristic_scales_beyond_truth_tables() -> Result<()> {
+    let input = "(A AND B AND C) OR (A AND B AND !C) OR (D AND E) OR (F AND G AND H) OR (I XOR J) \
+        OR (K AND L AND M AND N) OR (O AND P) OR (Q AND R AND S) OR (T AND U) OR (V AND W AND X AND Y AND Z)";
+    let expression: Expression = input.parse()?;
+    let cover = expression.minimize_heuristic();
+
+    assert_eq!(cover.variables.len(), 26);
+    assert_eq!(cover.cubes.len(), 10);
+    assert!(cover.cubes.contains(&Cube::new(0b11, 0b11)));
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::kmap::{KarnaughMap, KarnaughMapError};
+use logical_expression_pest_parser::truth_table::TruthTable;
+
+fn karnaugh_map(input: &str) -> Result<Result<KarnaughMap, KarnaughMapError>> {
+    let expression: Expression = input.parse()?;
+    Ok(KarnaughMap::new(&TruthTable::from(&expression)))
+}
+
+#[test]
+fn test_axes_are_gray_code_ordered() -> Result<()> {
+    let map = karnaugh_map("A AND B AND C AND D")??;
+
+    assert_eq!(map.row_variables_length, 2);
+    assert_eq!(
+        map.cells,
+        vec![
+            vec![0, 8, 12, 4],
+            vec![2, 10, 14, 6],
+            vec![3, 11, 15, 7],
+            vec![1, 9, 13, 5],
+        ]
+    );
+    assert!(map.values[2][2]);
+
+    Ok(())
+}
+
+#[test]
+fn test_unsupported_variable_counts() -> Result<()> {
+    assert_eq!(
+        karnaugh_map("A OR !A")?,
+        Err(KarnaughMapError::UnsupportedVariableCount(1))
+    );
+    assert_eq!(
+        karnaugh_map("A AND B AND C AND D AND E AND F AND G")?,
+        Err(KarnaughMapError::UnsupportedVariableCount(7))
+    );
+    assert!(karnaugh_map("A AND B AND C AND D AND E AND F")?.is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_text_rendering_marks_groups() -> Result<()> {
+    let map = karnaugh_map("A AND B OR NOT A AND NOT B")??;
+    let text = map.to_string();
+
+    assert_eq!(map.groups.len(), 2);
+    assert!(text.contains(" 1a"));
+    assert!(text.contains(" 1b"));
+    assert!(text.contains("Groups:"));
+    assert!(map.to_ansi().contains("\x1b["));
+
+    Ok(())
+}
+
+#[test]
+fn test_svg_rendering() -> Result<()> {
+    let map = karnaugh_map("NOT B AND NOT D OR A AND C")??;
+    let svg = map.to_svg();
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(svg.matches("rx=\"8\"").count(), 5);
+
+    Ok(())
+}
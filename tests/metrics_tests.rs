@@ -0,0 +1,72 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::metrics::{Metrics, Operator};
+use logical_expression_pest_parser::parser::parse;
+
+#[test]
+fn test_metrics_counts() -> Result<()> {
+    let metrics = "(A AND B) OR !(A XOR C)".parse::<Expression>()?.metrics();
+
+    assert_eq!(metrics.node_count, 8);
+    assert_eq!(metrics.depth, 4);
+    assert_eq!(metrics.literal_count, 4);
+    assert_eq!(metrics.variable_count, 3);
+    assert_eq!(metrics.operators[&Operator::And], 1);
+    assert_eq!(metrics.operators[&Operator::Or], 1);
+    assert_eq!(metrics.operators[&Operator::Not], 1);
+    assert_eq!(metrics.operators[&Operator::Xor], 1);
+    assert!(!metrics.operators.contains_key(&Operator::Nand));
+
+    Ok(())
+}
+
+#[test]
+fn test_implication_metrics() -> Result<()> {
+    let metrics = "NOT A -> B".parse::<Expression>()?.metrics();
+
+    assert_eq!(metrics.node_count, 4);
+    assert_eq!(metrics.operators[&Operator::Implies], 1);
+    assert_eq!(metrics.operators[&Operator::Not], 1);
+    assert!(!metrics.operators.contains_key(&Operator::Or));
+    assert_eq!(metrics.two_level_cost.products, 2);
+    assert_eq!(metrics.two_level_cost.literals, 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_two_level_cost() -> Result<()> {
+    let metrics = "(A OR B) AND (C OR D)".parse::<Expression>()?.metrics();
+
+    assert_eq!(metrics.two_level_cost.products, 4);
+    assert_eq!(metrics.two_level_cost.literals, 8);
+    assert_eq!(metrics.two_level_cost.cost(), 12);
+
+    Ok(())
+}
+
+#[test]
+fn test_double_negations() -> Result<()> {
+    let nested = Expression::Not(Box::new(Expression::Not(Box::new(Expression::Identifier(
+        'A',
+    )))));
+    assert_eq!(nested.metrics().double_negations, 1);
+
+    let input = "!!A OR !!!!B OR !C\n";
+    assert_eq!(
+        input
+            .trim()
+            .parse::<Expression>()?
+            .metrics()
+            .double_negations,
+        0
+    );
+
+    let specification_pair = parse(input)?.next().unwrap().into_inner().next().unwrap();
+    let expression_pair = specification_pair.into_inner().next().unwrap();
+    let metrics = Metrics::from_pair(expression_pair);
+    assert_eq!(metrics.double_negations, 3);
+    assert_eq!(metrics.literal_count, 3);
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::multi_output::{self, PlaCost};
+use logical_expression_pest_parser::truth_table::{TruthTable, TruthTableRow};
+
+fn table(variables: &[char], minterms: &[usize], dont_cares: &[usize]) -> TruthTable {
+    TruthTable {
+        variables: variables.to_vec(),
+        rows: (0..1 << variables.len())
+            .map(|row| {
+                TruthTableRow::new(
+                    (0..variables.len())
+                        .map(|index| row >> index & 1 == 1)
+                        .collect(),
+                    minterms.contains(&row),
+                )
+                .with_dont_care(dont_cares.contains(&row))
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_multi_output_sharing() -> Result<()> {
+    let tables: Vec<TruthTable> = ["A AND B OR C", "A AND B OR NOT C AND A", "B AND C"]
+        .iter()
+        .map(|input| Ok(TruthTable::from(&input.parse::<Expression>()?)))
+        .collect::<Result<_>>()?;
+    let minimization = multi_output::minimize(&tables);
+
+    assert_eq!(minimization.variables, vec!['A', 'B', 'C']);
+    assert_eq!(minimization.to_dnf(0).to_string(), "A AND B OR C");
+    assert_eq!(minimization.to_dnf(1).to_string(), "A AND NOT C OR A AND B");
+    assert_eq!(minimization.to_dnf(2).to_string(), "B AND C");
+    assert_eq!(minimization.shared_terms().len(), 1);
+    assert_eq!(
+        minimization.cost(),
+        PlaCost {
+            products: 4,
+            literals: 7,
+            connections: 5
+        }
+    );
+    assert_eq!(
+        PlaCost::separate(&tables).to_string(),
+        "5 products, 9 literals, 5 connections"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_multi_output_different_variables() -> Result<()> {
+    let tables = [
+        TruthTable::from(&"A AND B".parse::<Expression>()?),
+        TruthTable::from(&"A AND B AND C".parse::<Expression>()?),
+    ];
+    let minimization = multi_output::minimize(&tables);
+
+    assert_eq!(minimization.variables, vec!['A', 'B', 'C']);
+    assert_eq!(minimization.to_dnf(0).to_string(), "A AND B");
+    assert_eq!(minimization.to_dnf(1).to_string(), "A AND B AND C");
+
+    Ok(())
+}
+
+#[test]
+fn test_seven_segment_decoder() {
+    let variables = ['A', 'B', 'C', 'D'];
+    let dont_cares: Vec<usize> = (10..16).collect();
+    let segments: [&[usize]; 7] = [
+        &[0, 2, 3, 5, 6, 7, 8, 9],
+        &[0, 1, 2, 3, 4, 7, 8, 9],
+        &[0, 1, 3, 4, 5, 6, 7, 8, 9],
+        &[0, 2, 3, 5, 6, 8, 9],
+        &[0, 2, 6, 8],
+        &[0, 4, 5, 6, 8, 9],
+        &[2, 3, 4, 5, 6, 8, 9],
+    ];
+    let tables: Vec<TruthTable> = segments
+        .iter()
+        .map(|minterms| table(&variables, minterms, &dont_cares))
+        .collect();
+
+    let minimization = multi_output::minimize(&tables);
+
+    for (output, minterms) in segments.iter().enumerate() {
+        for row in 0..10 {
+            let covered = minimization.outputs[output]
+                .iter()
+                .any(|&term| minimization.terms[term].contains_minterm(row));
+            assert_eq!(
+                covered,
+                minterms.contains(&row),
+                "segment {} row {}",
+                output,
+                row
+            );
+        }
+    }
+
+    assert!(!minimization.shared_terms().is_empty());
+    assert!(minimization.cost().products < PlaCost::separate(&tables).products);
+}
+
+#[test]
+fn test_support_groups() -> Result<()> {
+    let tables: Vec<TruthTable> = [
+        "A AND B",
+        "C OR D",
+        "B XOR E",
+        "F AND NOT F OR G",
+        "A OR NOT A",
+    ]
+    .iter()
+    .map(|input| Ok(TruthTable::from(&input.parse::<Expression>()?)))
+    .collect::<Result<_>>()?;
+
+    assert_eq!(
+        multi_output::support_groups(&tables),
+        vec![vec![0, 2], vec![1], vec![3], vec![4]]
+    );
+
+    let minimization = multi_output::minimize(&tables);
+    assert_eq!(minimization.to_dnf(0).to_string(), "A AND B");
+    assert_eq!(minimization.to_dnf(3).to_string(), "G");
+    assert_eq!(minimization.to_dnf(4).to_string(), "1");
+
+    Ok(())
+}
+
+#[test]
+fn test_many_overlapping_outputs() {
+    let variables = ['A', 'B', 'C', 'D', 'E'];
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let segments: Vec<Vec<usize>> = (0..24)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (0..32).filter(|&row| state >> row & 1 == 1).collect()
+        })
+        .collect();
+    let tables: Vec<TruthTable> = segments
+        .iter()
+        .map(|minterms| table(&variables, minterms, &[]))
+        .collect();
+
+    let minimization = multi_output::minimize(&tables);
+
+    for (output, minterms) in segments.iter().enumerate() {
+        for row in 0..32 {
+            let covered = minimization.outputs[output]
+                .iter()
+                .any(|&term| minimization.terms[term].contains_minterm(row));
+            assert_eq!(
+                covered,
+                minterms.contains(&row),
+                "output {} row {}",
+                output,
+                row
+            );
+        }
+    }
+    assert!(minimization.cost().products <= PlaCost::separate(&tables).products);
+}
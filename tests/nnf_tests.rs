@@ -0,0 +1,57 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+mod common;
+use common::results;
+
+fn is_nnf(expression: &Expression) -> bool {
+    match expression {
+        Expression::Constant(_) | Expression::Identifier(_) => true,
+        Expression::Not(expr) => matches!(**expr, Expression::Identifier(_)),
+        Expression::And(left, right) | Expression::Or(left, right) => is_nnf(left) && is_nnf(right),
+        _ => false,
+    }
+}
+
+#[test]
+fn test_nnf_is_equivalent() -> Result<()> {
+    for input in [
+        "NOT (A AND B) OR A",
+        "!(A NAND B) NOR !C",
+        "!(A XOR B) AND (C XNOR !D)",
+        "!((A OR B) NOR (C NAND D))",
+        "!(A -> !B) AND (C -> D -> A)",
+    ] {
+        let expression: Expression = input.parse()?;
+        let nnf = expression.to_nnf();
+
+        assert!(is_nnf(&nnf), "{} is not in NNF", nnf);
+        assert_eq!(results(&nnf), results(&expression));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_nnf_de_morgan() -> Result<()> {
+    let expression: Expression = "NOT (A AND NOT B)".parse()?;
+    assert_eq!(expression.to_nnf().to_string(), "NOT A OR B");
+
+    Ok(())
+}
+
+#[test]
+fn test_display_round_trip() -> Result<()> {
+    for input in [
+        "A AND B OR C",
+        "A AND (B OR C)",
+        "NOT (A XOR B) NAND C",
+        "A NOR (B NOR C)",
+        "A XNOR (B XOR C)",
+    ] {
+        let expression: Expression = input.parse()?;
+        assert_eq!(expression.to_string(), input);
+        assert_eq!(expression.to_string().parse::<Expression>()?, expression);
+    }
+
+    Ok(())
+}
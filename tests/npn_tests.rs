@@ -0,0 +1,125 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::npn::{NPN_MAX_VARIABLES, NpnError, Symmetry};
+use logical_expression_pest_parser::truth_table::{TruthTable, TruthTableRow};
+use std::collections::HashSet;
+
+/// Builds a table over the first `length` letters whose row `r` has result bit `r` of `table`.
+fn table_from_bits(length: usize, table: u64) -> TruthTable {
+    TruthTable {
+        variables: ('A'..).take(length).collect(),
+        rows: (0..1usize << length)
+            .map(|row| {
+                TruthTableRow::new(
+                    (0..length).map(|index| row >> index & 1 == 1).collect(),
+                    table >> row & 1 == 1,
+                )
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_npn_class_counts() -> Result<()> {
+    for (length, expected) in [(1, 2), (2, 4), (3, 14)] {
+        let classes: HashSet<u64> = (0..1u64 << (1 << length))
+            .map(|table| Ok(table_from_bits(length, table).npn_canonical()?.table))
+            .collect::<Result<_>>()?;
+        assert_eq!(classes.len(), expected, "{} variables", length);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_maps_onto_canonical_table() -> Result<()> {
+    for input in [
+        "A AND B OR C",
+        "A XOR NOT B AND C",
+        "NOT A AND (B OR NOT C) XOR D",
+        "(A NAND B) NOR (C XNOR D)",
+    ] {
+        let table = TruthTable::from(&input.parse::<Expression>()?);
+        let canonical = table.npn_canonical()?;
+        let transform = &canonical.transform;
+
+        for row in 0..table.rows.len() {
+            let source: usize = (0..table.variables.len())
+                .filter(|&index| (row as u64 ^ transform.input_negations) >> index & 1 == 1)
+                .map(|index| 1 << transform.permutation[index])
+                .sum();
+            let expected = table.rows[source].result ^ transform.output_negation;
+            assert_eq!(canonical.table >> row & 1 == 1, expected, "{}", input);
+        }
+
+        let representative = TruthTable::from(&canonical.representative());
+        let canonical_table = canonical.to_truth_table();
+        for (left, right) in representative.rows.iter().zip(&canonical_table.rows) {
+            assert_eq!(left.result, right.result, "{}", input);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_npn_equivalence() -> Result<()> {
+    let table = |input: &str| -> Result<TruthTable> { Ok(TruthTable::from(&input.parse()?)) };
+
+    assert!(table("A AND B")?.is_npn_equivalent(&table("NOT A OR B")?)?);
+    assert!(table("A AND B")?.is_npn_equivalent(&table("C NOR D")?)?);
+    assert!(table("A XOR B XOR C")?.is_npn_equivalent(&table("A XNOR B XNOR C")?)?);
+    assert!(!table("A AND B")?.is_npn_equivalent(&table("A XOR B")?)?);
+    assert!(!table("A AND B")?.is_npn_equivalent(&table("A AND B AND C")?)?);
+
+    let canonical = "A AND B".parse::<Expression>()?.npn_canonical()?;
+    assert_eq!(canonical.to_hex(), "1");
+    assert_eq!(canonical.representative().to_string(), "NOT A AND NOT B");
+
+    Ok(())
+}
+
+#[test]
+fn test_symmetries() -> Result<()> {
+    let majority: Expression = "A AND B OR A AND C OR B AND C".parse()?;
+    let table = TruthTable::from(&majority);
+    assert_eq!(table.symmetric_groups(), vec![vec!['A', 'B', 'C']]);
+    assert!(table.symmetries().iter().all(|symmetry| !symmetry.negated));
+
+    let table = TruthTable::from(&"A AND NOT B OR C".parse::<Expression>()?);
+    assert_eq!(
+        table.symmetries(),
+        vec![Symmetry {
+            first: 'A',
+            second: 'B',
+            negated: true,
+        }]
+    );
+    assert_eq!(table.symmetries()[0].to_string(), "(A, NOT B)");
+    assert!(table.symmetric_groups().is_empty());
+
+    let table = TruthTable::from(&"A AND B OR C AND D".parse::<Expression>()?);
+    assert_eq!(
+        table.symmetric_groups(),
+        vec![vec!['A', 'B'], vec!['C', 'D']]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_too_many_variables() -> Result<()> {
+    let expression: Expression = "A AND B AND C AND D AND E AND F AND G".parse()?;
+    assert_eq!(
+        expression.npn_canonical(),
+        Err(NpnError::TooManyVariables {
+            variables: 7,
+            maximum: NPN_MAX_VARIABLES,
+        })
+    );
+
+    let expression: Expression = "A AND B OR C AND D OR E AND F".parse()?;
+    assert!(expression.npn_canonical().is_ok());
+
+    Ok(())
+}
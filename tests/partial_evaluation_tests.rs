@@ -0,0 +1,78 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::truth_table::TruthTable;
+use std::collections::HashMap;
+
+#[test]
+fn test_partial_evaluate_folds_to_constant() -> Result<()> {
+    let expression: Expression = "A AND B OR C".parse()?;
+    let residual = expression.partial_evaluate(&HashMap::from([('A', false), ('C', true)]));
+    assert_eq!(residual, Expression::Constant(true));
+
+    Ok(())
+}
+
+#[test]
+fn test_partial_evaluate_keeps_unassigned_variables() -> Result<()> {
+    let expression: Expression = "(A NAND B) XOR C".parse()?;
+    let residual = expression.partial_evaluate(&HashMap::from([('A', true), ('C', true)]));
+    assert_eq!(residual, "B".parse()?);
+
+    Ok(())
+}
+
+#[test]
+fn test_cofactors() -> Result<()> {
+    let expression: Expression = "(A AND B) NOR C".parse()?;
+    assert_eq!(
+        expression.positive_cofactor('C'),
+        Expression::Constant(false)
+    );
+    assert_eq!(expression.negative_cofactor('C'), "!(A AND B)".parse()?);
+
+    Ok(())
+}
+
+#[test]
+fn test_shannon_expansion() -> Result<()> {
+    let expression: Expression = "A XNOR (B OR !C)".parse()?;
+    let positive = expression.positive_cofactor('B');
+    let negative = expression.negative_cofactor('B');
+
+    for row in 0..8 {
+        let variables = HashMap::from([
+            ('A', row & 1 == 1),
+            ('B', row & 2 == 2),
+            ('C', row & 4 == 4),
+        ]);
+        let cofactor = if variables[&'B'] {
+            &positive
+        } else {
+            &negative
+        };
+        assert_eq!(
+            cofactor.evaluate(&variables),
+            expression.evaluate(&variables)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_constant_truth_table_has_one_row() -> Result<()> {
+    let residual = "A AND B".parse::<Expression>()?.positive_cofactor('A');
+    let constant = residual.negative_cofactor('B');
+
+    for table in [
+        TruthTable::from(&constant),
+        TruthTable::from_interpreted(&constant),
+    ] {
+        assert!(table.variables.is_empty());
+        assert_eq!(table.rows.len(), 1);
+        assert!(table.rows[0].values.is_empty());
+        assert!(!table.rows[0].result);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::post::{
+    PostClass, common_post_classes, is_functionally_complete,
+};
+use logical_expression_pest_parser::truth_table::TruthTable;
+
+/// Builds the truth tables of a set of connectives.
+fn tables(inputs: &[&str]) -> Result<Vec<TruthTable>> {
+    inputs
+        .iter()
+        .map(|input| Ok(TruthTable::from(&input.parse::<Expression>()?)))
+        .collect()
+}
+
+#[test]
+fn test_post_classes() -> Result<()> {
+    use PostClass::*;
+
+    for (input, expected) in [
+        ("A AND B", vec![PreservesZero, PreservesOne, Monotone]),
+        ("A OR B", vec![PreservesZero, PreservesOne, Monotone]),
+        ("NOT A", vec![SelfDual, Affine]),
+        (
+            "A",
+            vec![PreservesZero, PreservesOne, SelfDual, Monotone, Affine],
+        ),
+        ("A XOR B", vec![PreservesZero, Affine]),
+        ("A XNOR B", vec![PreservesOne, Affine]),
+        (
+            "A XOR B XOR C",
+            vec![PreservesZero, PreservesOne, SelfDual, Affine],
+        ),
+        ("A NAND B", vec![]),
+        ("A NOR B", vec![]),
+        ("A -> B", vec![PreservesOne]),
+        (
+            "A AND B OR A AND C OR B AND C",
+            vec![PreservesZero, PreservesOne, SelfDual, Monotone],
+        ),
+        ("A AND NOT A", vec![PreservesZero, Monotone, Affine]),
+    ] {
+        let expression: Expression = input.parse()?;
+        assert_eq!(expression.post_classes(), expected, "{}", input);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_constants() {
+    let table = TruthTable::from(&Expression::Constant(true));
+    assert_eq!(
+        table.post_classes(),
+        vec![
+            PostClass::PreservesOne,
+            PostClass::Monotone,
+            PostClass::Affine
+        ]
+    );
+    assert!(!table.is_self_dual());
+}
+
+#[test]
+fn test_functional_completeness() -> Result<()> {
+    assert!(is_functionally_complete(&tables(&["A NAND B"])?));
+    assert!(is_functionally_complete(&tables(&["A NOR B"])?));
+    assert!(is_functionally_complete(&tables(&["A AND B", "NOT A"])?));
+    assert!(is_functionally_complete(&tables(&[
+        "A -> B",
+        "A AND NOT A"
+    ])?));
+    assert!(is_functionally_complete(&tables(&[
+        "A XOR B", "A AND B", "A XNOR B"
+    ])?));
+
+    let monotone = tables(&["A AND B", "A OR B"])?;
+    assert!(!is_functionally_complete(&monotone));
+    assert_eq!(
+        common_post_classes(&monotone),
+        vec![
+            PostClass::PreservesZero,
+            PostClass::PreservesOne,
+            PostClass::Monotone
+        ]
+    );
+
+    let linear = tables(&["A XOR B", "NOT A"])?;
+    assert_eq!(common_post_classes(&linear), vec![PostClass::Affine]);
+
+    assert_eq!(common_post_classes(&[]), PostClass::ALL.to_vec());
+
+    Ok(())
+}
+
+#[test]
+fn test_display() {
+    let names: Vec<String> = PostClass::ALL.iter().map(PostClass::to_string).collect();
+    assert_eq!(
+        names,
+        [
+            "T0-preserving",
+            "T1-preserving",
+            "self-dual",
+            "monotone",
+            "affine"
+        ]
+    );
+}
@@ -0,0 +1,89 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::cube::Cube;
+use logical_expression_pest_parser::quine_mccluskey::{minimize, prime_implicants};
+mod common;
+use common::results;
+
+#[test]
+fn test_prime_implicants() -> Result<()> {
+    let primes = prime_implicants(3, &[0, 1, 2, 5, 6, 7], &[]);
+    let patterns: Vec<String> = primes.iter().map(|cube| cube.to_pattern(3)).collect();
+
+    assert_eq!(primes.len(), 6);
+    for pattern in ["-00", "0-0", "10-", "01-", "1-1", "-11"] {
+        assert!(
+            patterns.contains(&pattern.to_string()),
+            "missing {}",
+            pattern
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_cyclic_core_uses_petrick() -> Result<()> {
+    let minimization = minimize(&['A', 'B', 'C'], &[0, 1, 2, 5, 6, 7], &[]);
+
+    assert!(minimization.essential_prime_implicants.is_empty());
+    assert_eq!(minimization.cover.len(), 3);
+    assert_eq!(minimization.literal_count(), 6);
+
+    Ok(())
+}
+
+#[test]
+fn test_essential_prime_implicants() -> Result<()> {
+    let expression: Expression = "A AND B OR NOT A AND C OR B AND C".parse()?;
+    let minimization = expression.minimize();
+
+    assert_eq!(
+        minimization.essential_prime_implicants,
+        vec![Cube::new(0b011, 0b011), Cube::new(0b100, 0b101)]
+    );
+    assert_eq!(minimization.to_dnf().to_string(), "A AND B OR NOT A AND C");
+
+    Ok(())
+}
+
+#[test]
+fn test_minimization_is_equivalent() -> Result<()> {
+    for input in [
+        "(A XOR B) OR (C AND !D) OR (A AND B AND D)",
+        "!(A NAND B) NOR (C XNOR D)",
+        "A OR !A",
+        "A AND !A",
+    ] {
+        let expression: Expression = input.parse()?;
+        let minimized = expression.minimize().to_expression();
+        let variables = expression.variables();
+
+        for row in 0..1 << variables.len() {
+            let assignment = variables
+                .iter()
+                .enumerate()
+                .map(|(index, &variable)| (variable, row >> index & 1 == 1))
+                .collect();
+            assert_eq!(
+                minimized.evaluate(&assignment),
+                expression.evaluate(&assignment)
+            );
+        }
+    }
+
+    assert_eq!(
+        results(&"A OR !A".parse::<Expression>()?.minimize().to_expression()),
+        vec![true]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_dont_cares_enlarge_implicants() -> Result<()> {
+    let minimization = minimize(&['A', 'B'], &[1], &[3]);
+    assert_eq!(minimization.cover, vec![Cube::new(0b01, 0b01)]);
+
+    Ok(())
+}
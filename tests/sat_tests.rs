@@ -0,0 +1,148 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::sat::{Model, Solver};
+
+/// Builds the pigeonhole formula putting `pigeons` pigeons into `holes` holes, one variable per pair.
+fn pigeonhole(pigeons: usize, holes: usize) -> Result<Expression> {
+    let variable = |pigeon: usize, hole: usize| (b'A' + (pigeon * holes + hole) as u8) as char;
+
+    let mut constraints: Vec<String> = (0..pigeons)
+        .map(|pigeon| {
+            let holes: Vec<String> = (0..holes)
+                .map(|hole| variable(pigeon, hole).to_string())
+                .collect();
+            format!("({})", holes.join(" OR "))
+        })
+        .collect();
+    for hole in 0..holes {
+        for first in 0..pigeons {
+            for second in first + 1..pigeons {
+                constraints.push(format!(
+                    "({} NAND {})",
+                    variable(first, hole),
+                    variable(second, hole)
+                ));
+            }
+        }
+    }
+
+    Ok(constraints.join(" AND ").parse()?)
+}
+
+#[test]
+fn test_solve_matches_truth_table() -> Result<()> {
+    for input in [
+        "A",
+        "A AND NOT A",
+        "A OR NOT A",
+        "A XOR B XOR C",
+        "(A XOR B) AND (B XOR C) AND (A XOR C)",
+        "(A OR B) AND (NOT A OR C) AND (NOT B OR C) AND NOT C",
+        "(A NOR B) XNOR (C NAND D)",
+        "(NOT A OR B) AND (NOT B OR C) AND A AND NOT C",
+    ] {
+        let input: Expression = input.parse()?;
+        let model = input.solve();
+        assert_eq!(model.is_some(), input.is_satisfiable(), "{}", input);
+
+        if let Some(model) = model {
+            assert_eq!(model.variables, input.variables());
+            assert!(input.evaluate_lenient(&model), "{} with {}", input, model);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_pigeonhole_is_unsatisfiable() -> Result<()> {
+    assert!(pigeonhole(5, 4)?.solve().is_none());
+
+    let expression = pigeonhole(4, 4)?;
+    let model = expression.solve().unwrap();
+    assert!(expression.evaluate_lenient(&model));
+
+    Ok(())
+}
+
+#[test]
+fn test_large_expression() -> Result<()> {
+    let variables: Vec<String> = ('A'..='Z').map(|variable| variable.to_string()).collect();
+    let parity: Expression = variables.join(" XOR ").parse()?;
+    let chain: Expression = variables
+        .windows(2)
+        .map(|pair| format!("(NOT {} OR {})", pair[0], pair[1]))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+        .parse()?;
+    let expression = Expression::And(Box::new(parity), Box::new(chain));
+
+    let model = expression.solve().unwrap();
+    assert_eq!(model.variables.len(), 26);
+    assert!(expression.evaluate_lenient(&model));
+
+    let model = expression.solve_with_assumptions(&[('A', false)]).unwrap();
+    assert!(expression.evaluate_lenient(&model));
+    assert_eq!(expression.solve_with_assumptions(&[('A', true)]), None);
+    assert_eq!(expression.solve_with_assumptions(&[('Z', false)]), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_assumptions() -> Result<()> {
+    let expression: Expression = "A OR B".parse()?;
+
+    let model = expression.solve_with_assumptions(&[('A', false)]).unwrap();
+    assert_eq!(model.to_string(), "A = 0, B = 1");
+    assert_eq!(
+        expression.solve_with_assumptions(&[('A', false), ('B', false)]),
+        None
+    );
+
+    let model = expression
+        .solve_with_assumptions(&[('A', false), ('C', true)])
+        .unwrap();
+    assert_eq!(
+        model,
+        Model {
+            variables: vec!['A', 'B', 'C'],
+            values: vec![false, true, true],
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_incremental_solving() -> Result<()> {
+    let mut solver = Solver::new();
+    solver.add_expression(&"A XOR B".parse()?);
+    solver.add_expression(&"B XOR C".parse()?);
+
+    assert_eq!(
+        solver.solve_with_assumptions(&[('A', true), ('C', false)]),
+        None
+    );
+    let model = solver.solve_with_assumptions(&[('A', true)]).unwrap();
+    assert_eq!(model.to_string(), "A = 1, B = 0, C = 1");
+
+    solver.add_expression(&"A XOR C".parse()?);
+    assert_eq!(solver.solve(), None);
+    assert_eq!(solver.solve_with_assumptions(&[('B', true)]), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(
+        Expression::Constant(true).solve().unwrap().to_string(),
+        "no variables"
+    );
+    assert_eq!(Expression::Constant(false).solve(), None);
+
+    let mut solver = Solver::new();
+    solver.declare('B');
+    assert_eq!(solver.solve().unwrap().variables, vec!['B']);
+}
@@ -0,0 +1,90 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::simplify::Law;
+mod common;
+use common::results_over;
+
+fn simplify(input: &str) -> Result<String> {
+    let expression: Expression = input.parse()?;
+    Ok(expression.simplify().to_string())
+}
+
+#[test]
+fn test_simplify_with_steps() -> Result<()> {
+    let expression: Expression = "NOT (A AND B) OR A".parse()?;
+    let simplification = expression.simplify_with_steps();
+
+    let laws: Vec<Law> = simplification.steps.iter().map(|step| step.law).collect();
+    assert_eq!(laws, vec![Law::DeMorgan, Law::Complement]);
+
+    let first = &simplification.steps[0];
+    assert_eq!(first.before.to_string(), "NOT (A AND B)");
+    assert_eq!(first.after.to_string(), "NOT A OR NOT B");
+    assert_eq!(first.result.to_string(), "NOT A OR NOT B OR A");
+    assert_eq!(
+        first.to_string(),
+        "De Morgan: NOT (A AND B) => NOT A OR NOT B"
+    );
+
+    assert_eq!(simplification.result, Expression::Constant(true));
+    assert_eq!(
+        simplification.steps.last().unwrap().result,
+        simplification.result
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_simplify_laws() -> Result<()> {
+    assert_eq!(simplify("NOT NOT NOT A")?, "NOT A");
+    assert_eq!(simplify("A AND B AND A")?, "A AND B");
+    assert_eq!(simplify("A OR A AND B")?, "A");
+    assert_eq!(simplify("A AND (B OR A)")?, "A");
+    assert_eq!(simplify("A AND NOT A AND B")?, "0");
+    assert_eq!(simplify("A NOR B")?, "NOT A AND NOT B");
+    assert_eq!(
+        simplify("A AND B OR NOT A AND C OR B AND C")?,
+        "A AND B OR NOT A AND C"
+    );
+    assert_eq!(
+        simplify("(A OR B) AND (NOT A OR C) AND (B OR C)")?,
+        "(A OR B) AND (NOT A OR C)"
+    );
+    assert_eq!(simplify("(A XOR A) OR B")?, "B");
+    assert_eq!(simplify("A XOR NOT A")?, "1");
+    assert_eq!(simplify("A AND B OR C")?, "A AND B OR C");
+
+    Ok(())
+}
+
+#[test]
+fn test_simplify_is_equivalent() -> Result<()> {
+    for input in [
+        "NOT (A AND B) OR A",
+        "(A NAND B) XNOR A",
+        "!(A NOR !B) AND (C OR A AND C)",
+        "(A XOR B) AND NOT (A XOR B) OR C",
+        "A AND B OR NOT A AND C OR B AND C AND D",
+        "(A -> B) AND A -> B",
+    ] {
+        let expression: Expression = input.parse()?;
+        let variables = expression.variables();
+        let simplification = expression.simplify_with_steps();
+
+        assert_eq!(
+            results_over(&simplification.result, &variables),
+            results_over(&expression, &variables)
+        );
+        for step in &simplification.steps {
+            assert_eq!(
+                results_over(&step.before, &variables),
+                results_over(&step.after, &variables),
+                "{}",
+                step
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::truth_table::TruthTable;
+
+#[test]
+fn test_support_drops_vacuous_variables() -> Result<()> {
+    let expression: Expression = "A OR (B AND NOT B)".parse()?;
+
+    assert_eq!(expression.variables(), vec!['A', 'B']);
+    assert_eq!(expression.support(), vec!['A']);
+    assert_eq!(expression.vacuous_variables(), vec!['B']);
+    assert!(expression.depends_on('A'));
+    assert!(!expression.depends_on('B'));
+    assert!(!expression.depends_on('Z'));
+
+    Ok(())
+}
+
+#[test]
+fn test_truth_table_without_vacuous_variables() -> Result<()> {
+    let expression: Expression = "(A XOR C) OR (B AND !B)".parse()?;
+    let truth_table = TruthTable::from(&expression).without_vacuous_variables();
+
+    assert_eq!(truth_table.variables, vec!['A', 'C']);
+    let results: Vec<bool> = truth_table.rows.iter().map(|row| row.result).collect();
+    assert_eq!(results, vec![false, true, true, false]);
+    assert_eq!(truth_table.rows[1].values, vec![true, false]);
+
+    Ok(())
+}
+
+#[test]
+fn test_constant_function_has_empty_support() -> Result<()> {
+    let expression: Expression = "A XNOR A".parse()?;
+    let truth_table = TruthTable::from(&expression).without_vacuous_variables();
+
+    assert!(expression.support().is_empty());
+    assert!(truth_table.variables.is_empty());
+    assert_eq!(truth_table.rows.len(), 1);
+    assert!(truth_table.rows[0].result);
+
+    Ok(())
+}
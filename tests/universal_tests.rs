@@ -0,0 +1,87 @@
+use anyhow::Result;
+use logical_expression_pest_parser::ast::Expression;
+use logical_expression_pest_parser::universal::UniversalGate;
+mod common;
+use common::results;
+
+fn uses_only(expression: &Expression, gate: UniversalGate) -> bool {
+    match (expression, gate) {
+        (Expression::Constant(_) | Expression::Identifier(_), _) => true,
+        (Expression::Nand(left, right), UniversalGate::Nand)
+        | (Expression::Nor(left, right), UniversalGate::Nor) => {
+            uses_only(left, gate) && uses_only(right, gate)
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test_universal_is_equivalent() -> Result<()> {
+    for input in [
+        "NOT (A AND B) OR A",
+        "!(A NAND B) NOR !C",
+        "!(A XOR B) AND (C XNOR !D)",
+        "!((A OR B) NOR (C NAND D))",
+        "A XOR B XOR C",
+        "!(A -> B) OR (C -> A)",
+    ] {
+        let expression: Expression = input.parse()?;
+
+        for gate in [UniversalGate::Nand, UniversalGate::Nor] {
+            let converted = expression.to_universal(gate);
+
+            assert!(
+                uses_only(&converted, gate),
+                "{} is not {}-only",
+                converted,
+                gate
+            );
+            assert_eq!(results(&converted), results(&expression));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_nand_only() -> Result<()> {
+    let expression: Expression = "A AND B".parse()?;
+    let nand = expression.to_nand_only();
+    assert_eq!(nand.to_string(), "A NAND B NAND (A NAND B)");
+    assert_eq!(nand.gate_count(), 2);
+
+    let expression: Expression = "NOT (A AND B)".parse()?;
+    assert_eq!(expression.to_nand_only().to_string(), "A NAND B");
+
+    let expression: Expression = "A XOR B".parse()?;
+    assert_eq!(expression.to_nand_only().gate_count(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_nor_only() -> Result<()> {
+    let expression: Expression = "A OR B".parse()?;
+    let nor = expression.to_nor_only();
+    assert_eq!(nor.to_string(), "A NOR B NOR (A NOR B)");
+    assert_eq!(nor.gate_count(), 2);
+
+    let expression: Expression = "NOT A AND NOT B".parse()?;
+    assert_eq!(expression.to_nor_only().to_string(), "A NOR B");
+
+    let expression: Expression = "A XNOR B".parse()?;
+    assert_eq!(expression.to_nor_only().gate_count(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_gate_count() -> Result<()> {
+    let expression: Expression = "NOT (A AND B) OR (A AND B)".parse()?;
+    assert_eq!(expression.gate_count(), 3);
+
+    let expression: Expression = "A".parse()?;
+    assert_eq!(expression.gate_count(), 0);
+
+    Ok(())
+}